@@ -60,6 +60,7 @@ async fn main() -> Result<()> {
             EventType::RaydiumCpmmSwapBaseInput,
             EventType::RaydiumCpmmSwapBaseOutput,
         ],
+        exclude: vec![],
     };
 
     if let Err(e) = client
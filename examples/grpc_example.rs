@@ -24,10 +24,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     println!("Subscribing to Yellowstone gRPC events...");
 
-    // Create low-latency configuration
-    let mut config: ClientConfig = ClientConfig::default();
     // Enable performance monitoring, has performance overhead, disabled by default
-    config.enable_metrics = true;
+    let config = ClientConfig::builder().metrics(true).build()?;
     let grpc = YellowstoneGrpc::new_with_config(
         "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
         None,
@@ -79,7 +77,7 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     // No event filtering, includes all events
     let event_type_filter = None;
     // Only include PumpSwapBuy events and PumpSwapSell events
-    // let event_type_filter = Some(EventTypeFilter { include: vec![EventType::PumpFunTrade] });
+    // let event_type_filter = Some(EventTypeFilter { include: vec![EventType::PumpFunTrade], exclude: vec![] });
 
     println!("Starting to listen for events, press Ctrl+C to stop...");
     println!("Monitoring programs: {:?}", account_include);
@@ -73,7 +73,8 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Event filtering
-    let event_type_filter = Some(EventTypeFilter { include: vec![EventType::TokenAccount] });
+    let event_type_filter =
+        Some(EventTypeFilter { include: vec![EventType::TokenAccount], exclude: vec![] });
 
     println!("Starting to listen for events, press Ctrl+C to stop...");
     println!("Starting subscription...");
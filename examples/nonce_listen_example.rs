@@ -46,7 +46,8 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
         AccountFilter { account: vec![nonce_account], owner: vec![], filters: vec![] };
 
     // Event filtering
-    let event_type_filter = Some(EventTypeFilter { include: vec![EventType::NonceAccount] });
+    let event_type_filter =
+        Some(EventTypeFilter { include: vec![EventType::NonceAccount], exclude: vec![] });
 
     println!("Starting to listen for events, press Ctrl+C to stop...");
     println!("Starting subscription...");
@@ -10,11 +10,6 @@ use solana_streamer_sdk::streaming::{
     yellowstone_grpc::{AccountFilter, TransactionFilter},
     YellowstoneGrpc,
 };
-use yellowstone_grpc_proto::geyser::{
-    subscribe_request_filter_accounts_filter::Filter,
-    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
-    SubscribeRequestFilterAccountsFilterMemcmp,
-};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -56,26 +51,17 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     let pump_usdc_account_filter = AccountFilter {
         account: vec![],
         owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 32,
-                data: Some(Data::Bytes(pump_usdc.to_bytes().to_vec())),
-            })),
-        }],
+        filters: vec![AccountFilter::memcmp(32, pump_usdc.to_bytes())],
     };
     let wsol_deepseekai_account_filter = AccountFilter {
         account: vec![],
         owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 32,
-                data: Some(Data::Bytes(wsol_deepseekai.to_bytes().to_vec())),
-            })),
-        }],
+        filters: vec![AccountFilter::memcmp(32, wsol_deepseekai.to_bytes())],
     };
 
     // Event filtering
-    let event_type_filter = Some(EventTypeFilter { include: vec![EventType::TokenAccount] });
+    let event_type_filter =
+        Some(EventTypeFilter { include: vec![EventType::TokenAccount], exclude: vec![] });
 
     println!("Starting to listen for events, press Ctrl+C to stop...");
     println!("Starting subscription...");
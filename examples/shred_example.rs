@@ -36,7 +36,7 @@ async fn test_shreds() -> Result<(), Box<dyn std::error::Error>> {
     let event_type_filter = None;
     // Only include PumpSwapBuy events and PumpSwapSell events
     // let event_type_filter =
-    //     EventTypeFilter { include: vec![EventType::PumpSwapBuy, EventType::PumpSwapSell] };
+    //     EventTypeFilter { include: vec![EventType::PumpSwapBuy, EventType::PumpSwapSell], exclude: vec![] };
 
     println!("Listening for events, press Ctrl+C to stop...");
     shred_stream.shredstream_subscribe(protocols, None, event_type_filter, callback).await?;
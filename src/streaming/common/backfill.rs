@@ -0,0 +1,275 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use futures::{stream, StreamExt};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+
+use crate::common::AnyResult;
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::core::dispatcher::EventDispatcher;
+use crate::streaming::event_parser::core::event_parser::EventParser;
+use crate::streaming::event_parser::DexEvent;
+
+/// Historical backfill configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackfillConfig {
+    /// Maximum concurrent `getTransaction` requests (default: 8)
+    pub concurrency: usize,
+    /// Signatures requested per `getSignaturesForAddress` page (default: 1000)
+    pub page_size: usize,
+    /// Commitment used for RPC requests (default: confirmed)
+    pub commitment: CommitmentConfig,
+    /// Start paging from this signature instead of the chain tip (default: None)
+    ///
+    /// `getSignaturesForAddress` only pages backwards, so without it every signature newer than
+    /// `to_slot` is fetched and discarded. Pass a signature at or just after `to_slot` when
+    /// backfilling a range far from the tip.
+    pub before: Option<Signature>,
+}
+
+impl Default for BackfillConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            page_size: 1000,
+            commitment: CommitmentConfig::confirmed(),
+            before: None,
+        }
+    }
+}
+
+/// Replay successful transactions of `program_ids` in `[from_slot, to_slot]` through the event
+/// parser, oldest first. Returns the number of transactions processed.
+///
+/// Paging starts at `config.before` (the chain tip by default) and stops once a page reaches
+/// below `from_slot`.
+pub async fn backfill_transactions(
+    rpc_client: &RpcClient,
+    config: &BackfillConfig,
+    program_ids: &[Pubkey],
+    from_slot: u64,
+    to_slot: u64,
+    event_type_filter: Option<&EventTypeFilter>,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+) -> AnyResult<usize> {
+    let protocols: Vec<_> =
+        program_ids.iter().filter_map(EventDispatcher::match_protocol_by_program_id).collect();
+
+    let mut seen = HashSet::new();
+    let mut signatures = Vec::new();
+    for program_id in program_ids {
+        for (slot, signature) in
+            collect_signatures(rpc_client, config, program_id, from_slot, to_slot).await?
+        {
+            if seen.insert(signature) {
+                signatures.push((slot, signature));
+            }
+        }
+    }
+    // RPC 按从新到旧返回，回放时按 slot 从旧到新
+    signatures.reverse();
+    signatures.sort_by_key(|(slot, _)| *slot);
+
+    let tx_config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(config.commitment),
+        max_supported_transaction_version: Some(0),
+    };
+    let mut transactions = stream::iter(signatures)
+        .map(|(_, signature)| async move {
+            let result = rpc_client.get_transaction_with_config(&signature, tx_config).await;
+            (signature, result)
+        })
+        .buffered(config.concurrency.max(1));
+
+    let mut processed = 0;
+    while let Some((signature, result)) = transactions.next().await {
+        let transaction = match result {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                log::error!("Failed to fetch transaction {signature}: {e}");
+                continue;
+            }
+        };
+        if let Err(e) = EventParser::parse_encoded_confirmed_transaction(
+            &protocols,
+            event_type_filter,
+            transaction,
+            signature,
+            get_high_perf_clock(),
+            None,
+            callback.clone(),
+        )
+        .await
+        {
+            log::error!("Failed to parse transaction {signature}: {e:?}");
+            continue;
+        }
+        processed += 1;
+    }
+    Ok(processed)
+}
+
+/// 分页获取 `[from_slot, to_slot]` 内成功交易的签名，按从新到旧排列
+async fn collect_signatures(
+    rpc_client: &RpcClient,
+    config: &BackfillConfig,
+    program_id: &Pubkey,
+    from_slot: u64,
+    to_slot: u64,
+) -> AnyResult<Vec<(u64, Signature)>> {
+    let mut signatures = Vec::new();
+    let mut before = config.before;
+    loop {
+        let page = rpc_client
+            .get_signatures_for_address_with_config(
+                program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(config.page_size),
+                    commitment: Some(config.commitment),
+                },
+            )
+            .await?;
+        let Some(last) = page.last() else {
+            break;
+        };
+        let reached_start = last.slot < from_slot;
+        let page_len = page.len();
+        before = Some(Signature::from_str(&last.signature)?);
+
+        for status in page {
+            if status.slot < from_slot || status.slot > to_slot || status.err.is_some() {
+                continue;
+            }
+            signatures.push((status.slot, Signature::from_str(&status.signature)?));
+        }
+        if reached_start || page_len < config.page_size {
+            break;
+        }
+    }
+    Ok(signatures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::{
+        discriminators, parser::PUMPFUN_PROGRAM_ID,
+    };
+    use base64::Engine;
+    use parking_lot::Mutex;
+    use solana_rpc_client::mock_sender::MocksMap;
+    use solana_rpc_client_api::request::RpcRequest;
+    use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+    use solana_sdk::message::compiled_instruction::CompiledInstruction;
+    use solana_sdk::message::{Message, MessageHeader, VersionedMessage};
+    use solana_sdk::transaction::VersionedTransaction;
+    use solana_transaction_status::{
+        EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+        EncodedTransactionWithStatusMeta, TransactionBinaryEncoding,
+    };
+
+    /// 构造一笔包含 PumpFun buy 指令的交易
+    fn pumpfun_buy(signature: Signature, slot: u64, amount: u64) -> serde_json::Value {
+        // 第一个账户为付款人，最后一个为 PumpFun 程序
+        let mut account_keys: Vec<_> = (0..16).map(|_| Pubkey::new_unique()).collect();
+        account_keys.push(PUMPFUN_PROGRAM_ID);
+        let mut data = discriminators::BUY_IX.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        let transaction = VersionedTransaction {
+            signatures: vec![signature],
+            message: VersionedMessage::Legacy(Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys,
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 16,
+                    accounts: (0..16).collect(),
+                    data,
+                }],
+                ..Default::default()
+            }),
+        };
+        let encoded = base64::engine::general_purpose::STANDARD
+            .encode(bincode::serialize(&transaction).unwrap());
+        serde_json::to_value(EncodedConfirmedTransactionWithStatusMeta {
+            slot,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: EncodedTransaction::Binary(encoded, TransactionBinaryEncoding::Base64),
+                meta: None,
+                version: None,
+            },
+            block_time: None,
+        })
+        .unwrap()
+    }
+
+    fn signature_status(
+        signature: Signature,
+        slot: u64,
+    ) -> RpcConfirmedTransactionStatusWithSignature {
+        RpcConfirmedTransactionStatusWithSignature {
+            signature: signature.to_string(),
+            slot,
+            err: None,
+            memo: None,
+            block_time: None,
+            confirmation_status: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn backfill_parses_every_signature_oldest_first() {
+        let (older, newer) = (Signature::new_unique(), Signature::new_unique());
+        let mut mocks = MocksMap::default();
+        // RPC 按从新到旧返回签名
+        mocks.insert(
+            RpcRequest::GetSignaturesForAddress,
+            serde_json::to_value(vec![signature_status(newer, 11), signature_status(older, 10)])
+                .unwrap(),
+        );
+        mocks.insert(RpcRequest::GetTransaction, pumpfun_buy(older, 10, 1));
+        mocks.insert(RpcRequest::GetTransaction, pumpfun_buy(newer, 11, 2));
+        let rpc_client = RpcClient::new_mock_with_mocks_map("succeeds", mocks);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let config = BackfillConfig { concurrency: 1, ..Default::default() };
+        let processed = backfill_transactions(
+            &rpc_client,
+            &config,
+            &[PUMPFUN_PROGRAM_ID],
+            10,
+            11,
+            None,
+            Arc::new(move |event| sink.lock().push(event)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(processed, 2);
+        let events = events.lock();
+        let parsed: Vec<_> = events
+            .iter()
+            .map(|event| match event {
+                DexEvent::PumpFunTradeEvent(trade) => (trade.metadata.signature, trade.amount),
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(parsed, vec![(older, 1), (newer, 2)]);
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use super::constants::DEFAULT_CHANNEL_SIZE;
+use crate::streaming::event_parser::DexEvent;
+
+/// 队列已满时的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// 丢弃队列中最旧的事件
+    DropOldest,
+    /// 等待队列腾出空间，接收端随之减速
+    #[default]
+    Block,
+}
+
+/// Callback worker pool configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallbackPoolConfig {
+    /// Maximum number of queued events (default: 1000)
+    pub queue_depth: usize,
+    /// Number of worker tasks running the callback (default: 1)
+    ///
+    /// Events are delivered in order only with a single worker.
+    pub workers: usize,
+    /// Behaviour when the queue is full (default: Block)
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for CallbackPoolConfig {
+    fn default() -> Self {
+        Self { queue_depth: DEFAULT_CHANNEL_SIZE, workers: 1, overflow: OverflowPolicy::default() }
+    }
+}
+
+/// 回调线程池的队列指标
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CallbackPoolMetrics {
+    /// 当前排队的事件数
+    pub queue_depth: usize,
+    /// 队列容量
+    pub capacity: usize,
+    /// 因队列已满被丢弃的事件数
+    pub dropped_events: u64,
+    /// 已交给回调处理的事件数
+    pub delivered_events: u64,
+    /// 回调 panic 的事件数，已计入 `delivered_events`
+    pub panicked_events: u64,
+}
+
+/// 回调线程池及其工作任务
+pub type CallbackWorkers = (Arc<CallbackPool>, Vec<JoinHandle<()>>);
+
+/// 有界回调线程池，把用户回调从接收路径上移开
+pub struct CallbackPool {
+    queue: Mutex<VecDeque<DexEvent>>,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    items: Notify,
+    space: Notify,
+    idle: Notify,
+    closed: AtomicBool,
+    pushed_events: AtomicU64,
+    dropped_events: AtomicU64,
+    delivered_events: AtomicU64,
+    panicked_events: AtomicU64,
+}
+
+impl CallbackPool {
+    /// 创建线程池并启动工作任务
+    pub fn spawn(
+        config: &CallbackPoolConfig,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> (Arc<Self>, Vec<JoinHandle<()>>) {
+        let capacity = config.queue_depth.max(1);
+        let pool = Arc::new(Self {
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            overflow: config.overflow,
+            items: Notify::new(),
+            space: Notify::new(),
+            idle: Notify::new(),
+            closed: AtomicBool::new(false),
+            pushed_events: AtomicU64::new(0),
+            dropped_events: AtomicU64::new(0),
+            delivered_events: AtomicU64::new(0),
+            panicked_events: AtomicU64::new(0),
+        });
+        let handles = (0..config.workers.max(1))
+            .map(|_| {
+                let pool = pool.clone();
+                let callback = callback.clone();
+                tokio::spawn(async move {
+                    while let Some(event) = pool.pop().await {
+                        // 回调 panic 时工作任务继续运行，否则 drain 会一直等待
+                        if catch_unwind(AssertUnwindSafe(|| callback(event))).is_err() {
+                            log::error!("Event callback panicked");
+                            pool.panicked_events.fetch_add(1, Ordering::Relaxed);
+                        }
+                        pool.delivered_events.fetch_add(1, Ordering::Release);
+                        pool.idle.notify_waiters();
+                    }
+                })
+            })
+            .collect();
+        (pool, handles)
+    }
+
+    /// 入队事件，按 `OverflowPolicy` 处理队列已满的情况
+    pub async fn push(&self, event: DexEvent) {
+        self.pushed_events.fetch_add(1, Ordering::Relaxed);
+        loop {
+            let space = self.space.notified();
+            {
+                let mut queue = self.queue.lock();
+                if queue.len() < self.capacity {
+                    queue.push_back(event);
+                    break;
+                }
+                if self.overflow == OverflowPolicy::DropOldest {
+                    queue.pop_front();
+                    queue.push_back(event);
+                    self.dropped_events.fetch_add(1, Ordering::Release);
+                    break;
+                }
+            }
+            space.await;
+        }
+        self.items.notify_one();
+    }
+
+    /// 取出下一个事件，线程池关闭且队列已空时返回 None
+    async fn pop(&self) -> Option<DexEvent> {
+        loop {
+            let items = self.items.notified();
+            if let Some(event) = self.queue.lock().pop_front() {
+                self.space.notify_one();
+                return Some(event);
+            }
+            if self.closed.load(Ordering::Acquire) {
+                return None;
+            }
+            items.await;
+        }
+    }
+
+    /// 关闭线程池，工作任务处理完已入队的事件后退出
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.items.notify_waiters();
+    }
+
+    /// 等待已入队的事件全部交给回调处理完毕
+    pub async fn drain(&self) {
+        loop {
+            let idle = self.idle.notified();
+            let pushed = self.pushed_events.load(Ordering::Relaxed);
+            let finished = self.delivered_events.load(Ordering::Acquire)
+                + self.dropped_events.load(Ordering::Acquire);
+            if finished >= pushed {
+                return;
+            }
+            idle.await;
+        }
+    }
+
+    /// 获取队列指标
+    pub fn metrics(&self) -> CallbackPoolMetrics {
+        CallbackPoolMetrics {
+            queue_depth: self.queue.lock().len(),
+            capacity: self.capacity,
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            delivered_events: self.delivered_events.load(Ordering::Relaxed),
+            panicked_events: self.panicked_events.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::BlockMetaEvent;
+    use std::time::Duration;
+
+    const EVENTS: u64 = 10;
+
+    fn block_meta(slot: u64) -> DexEvent {
+        DexEvent::BlockMetaEvent(BlockMetaEvent::new(slot, String::new(), 0, 0))
+    }
+
+    async fn run_slow_callback(overflow: OverflowPolicy) -> CallbackPoolMetrics {
+        let config = CallbackPoolConfig { queue_depth: 2, workers: 1, overflow };
+        let (pool, handles) = CallbackPool::spawn(
+            &config,
+            Arc::new(|_| std::thread::sleep(Duration::from_millis(5))),
+        );
+        for slot in 0..EVENTS {
+            pool.push(block_meta(slot)).await;
+        }
+        pool.drain().await;
+        handles.iter().for_each(JoinHandle::abort);
+        pool.metrics()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn drop_oldest_sheds_events_behind_slow_callback() {
+        let metrics = run_slow_callback(OverflowPolicy::DropOldest).await;
+        assert!(metrics.dropped_events > 0);
+        assert_eq!(metrics.dropped_events + metrics.delivered_events, EVENTS);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn block_delivers_every_event_behind_slow_callback() {
+        let metrics = run_slow_callback(OverflowPolicy::Block).await;
+        assert_eq!(metrics.dropped_events, 0);
+        assert_eq!(metrics.delivered_events, EVENTS);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn close_lets_workers_finish_the_queue_and_exit() {
+        let delivered = Arc::new(AtomicU64::new(0));
+        let counter = delivered.clone();
+        let (pool, handles) = CallbackPool::spawn(
+            &CallbackPoolConfig { workers: 2, ..Default::default() },
+            Arc::new(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+        for slot in 0..EVENTS {
+            pool.push(block_meta(slot)).await;
+        }
+        pool.close();
+        tokio::time::timeout(Duration::from_secs(5), futures::future::join_all(handles))
+            .await
+            .expect("workers should exit once the pool is closed");
+        assert_eq!(delivered.load(Ordering::Relaxed), EVENTS);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn panicking_callback_does_not_stall_drain() {
+        let (pool, handles) = CallbackPool::spawn(
+            &CallbackPoolConfig::default(),
+            Arc::new(|event| {
+                if event.metadata().slot % 2 == 0 {
+                    panic!("callback failure");
+                }
+            }),
+        );
+        for slot in 0..EVENTS {
+            pool.push(block_meta(slot)).await;
+        }
+        tokio::time::timeout(Duration::from_secs(5), pool.drain())
+            .await
+            .expect("drain should not wait on a panicked callback");
+        handles.iter().for_each(JoinHandle::abort);
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.delivered_events, EVENTS);
+        assert_eq!(metrics.panicked_events, EVENTS / 2);
+    }
+}
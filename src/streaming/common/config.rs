@@ -1,7 +1,12 @@
+use anyhow::anyhow;
+
+use super::backfill::BackfillConfig;
+use super::callback_pool::CallbackPoolConfig;
 use super::constants::*;
+use crate::common::AnyResult;
 
 /// Connection configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ConnectionConfig {
     /// Connection timeout in seconds (default: 10)
     pub connect_timeout: u64,
@@ -21,17 +26,193 @@ impl Default for ConnectionConfig {
     }
 }
 
+/// Reconnect configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectConfig {
+    /// Initial backoff in milliseconds, doubled after each failed attempt (default: 500)
+    pub initial_backoff_ms: u64,
+    /// Maximum backoff in milliseconds (default: 30000)
+    pub max_backoff_ms: u64,
+    /// Maximum consecutive reconnect attempts, unlimited if None (default: None)
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: DEFAULT_RECONNECT_INITIAL_BACKOFF_MS,
+            max_backoff_ms: DEFAULT_RECONNECT_MAX_BACKOFF_MS,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    /// Backoff before the given reconnect attempt (1-based)
+    pub fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let shift = attempt.saturating_sub(1).min(16);
+        let ms = self.initial_backoff_ms.saturating_mul(1 << shift).min(self.max_backoff_ms);
+        std::time::Duration::from_millis(ms)
+    }
+}
+
 /// Common client configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StreamClientConfig {
     /// Connection configuration
     pub connection: ConnectionConfig,
     /// Whether performance monitoring is enabled (default: false)
     pub enable_metrics: bool,
+    /// Reconnect configuration used by resilient subscriptions
+    pub reconnect: ReconnectConfig,
+    /// Run callbacks on a bounded worker pool instead of the receive task (default: None)
+    pub callback_pool: Option<CallbackPoolConfig>,
+    /// Historical backfill configuration
+    pub backfill: BackfillConfig,
 }
 
 impl Default for StreamClientConfig {
     fn default() -> Self {
-        Self { connection: ConnectionConfig::default(), enable_metrics: false }
+        Self {
+            connection: ConnectionConfig::default(),
+            enable_metrics: false,
+            reconnect: ReconnectConfig::default(),
+            callback_pool: None,
+            backfill: BackfillConfig::default(),
+        }
+    }
+}
+
+impl StreamClientConfig {
+    /// 创建配置构建器
+    pub fn builder() -> StreamClientConfigBuilder {
+        StreamClientConfigBuilder::default()
+    }
+}
+
+/// `StreamClientConfig` 构建器
+#[derive(Debug, Clone, Default)]
+pub struct StreamClientConfigBuilder {
+    config: StreamClientConfig,
+}
+
+impl StreamClientConfigBuilder {
+    /// 是否开启性能统计
+    pub fn metrics(mut self, enable: bool) -> Self {
+        self.config.enable_metrics = enable;
+        self
+    }
+
+    /// 连接超时（秒）
+    pub fn connect_timeout(mut self, secs: u64) -> Self {
+        self.config.connection.connect_timeout = secs;
+        self
+    }
+
+    /// 请求超时（秒）
+    pub fn request_timeout(mut self, secs: u64) -> Self {
+        self.config.connection.request_timeout = secs;
+        self
+    }
+
+    /// 最大解码消息大小（字节）
+    pub fn max_decoding_message_size(mut self, bytes: usize) -> Self {
+        self.config.connection.max_decoding_message_size = bytes;
+        self
+    }
+
+    /// 重连配置
+    pub fn reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.config.reconnect = reconnect;
+        self
+    }
+
+    /// 回调线程池配置
+    pub fn callback_pool(mut self, callback_pool: CallbackPoolConfig) -> Self {
+        self.config.callback_pool = Some(callback_pool);
+        self
+    }
+
+    /// 历史回放配置
+    pub fn backfill(mut self, backfill: BackfillConfig) -> Self {
+        self.config.backfill = backfill;
+        self
+    }
+
+    /// 校验并生成配置
+    pub fn build(self) -> AnyResult<StreamClientConfig> {
+        let connection = &self.config.connection;
+        if connection.connect_timeout == 0 || connection.request_timeout == 0 {
+            return Err(anyhow!("Timeouts must be greater than zero"));
+        }
+        if connection.max_decoding_message_size == 0 {
+            return Err(anyhow!("max_decoding_message_size must be greater than zero"));
+        }
+        if let Some(callback_pool) = &self.config.callback_pool {
+            if callback_pool.queue_depth == 0 || callback_pool.workers == 0 {
+                return Err(anyhow!(
+                    "Callback pool queue_depth and workers must be greater than zero"
+                ));
+            }
+        }
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::common::OverflowPolicy;
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn builder_matches_manual_field_assignment() {
+        let reconnect = ReconnectConfig { max_attempts: Some(5), ..Default::default() };
+        let callback_pool = CallbackPoolConfig {
+            queue_depth: 64,
+            workers: 2,
+            overflow: OverflowPolicy::DropOldest,
+        };
+        let backfill = BackfillConfig { concurrency: 4, ..Default::default() };
+
+        let built = StreamClientConfig::builder()
+            .metrics(true)
+            .connect_timeout(5)
+            .request_timeout(30)
+            .max_decoding_message_size(1024)
+            .reconnect(reconnect.clone())
+            .callback_pool(callback_pool.clone())
+            .backfill(backfill.clone())
+            .build()
+            .unwrap();
+
+        let mut manual = StreamClientConfig::default();
+        manual.enable_metrics = true;
+        manual.connection.connect_timeout = 5;
+        manual.connection.request_timeout = 30;
+        manual.connection.max_decoding_message_size = 1024;
+        manual.reconnect = reconnect;
+        manual.callback_pool = Some(callback_pool);
+        manual.backfill = backfill;
+        assert_eq!(built, manual);
+    }
+
+    #[test]
+    fn backoff_doubles_and_stops_at_the_cap() {
+        let reconnect =
+            ReconnectConfig { initial_backoff_ms: 100, max_backoff_ms: 1000, max_attempts: None };
+        let backoffs: Vec<u64> =
+            (1..=6).map(|attempt| reconnect.backoff(attempt).as_millis() as u64).collect();
+        assert_eq!(backoffs, vec![100, 200, 400, 800, 1000, 1000]);
+        // 极大的重连次数不会溢出
+        assert_eq!(reconnect.backoff(u32::MAX).as_millis(), 1000);
+    }
+
+    #[test]
+    fn build_rejects_zero_values() {
+        assert!(StreamClientConfig::builder().connect_timeout(0).build().is_err());
+        assert!(StreamClientConfig::builder().max_decoding_message_size(0).build().is_err());
+        let callback_pool = CallbackPoolConfig { workers: 0, ..Default::default() };
+        assert!(StreamClientConfig::builder().callback_pool(callback_pool).build().is_err());
     }
 }
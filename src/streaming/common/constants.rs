@@ -6,6 +6,16 @@ pub const DEFAULT_REQUEST_TIMEOUT: u64 = 60;
 pub const DEFAULT_CHANNEL_SIZE: usize = 1000;
 pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 1024 * 1024 * 10;
 
+// 断线重连相关常量
+pub const DEFAULT_RECONNECT_INITIAL_BACKOFF_MS: u64 = 500;
+pub const DEFAULT_RECONNECT_MAX_BACKOFF_MS: u64 = 30_000;
+
+// 未设置超时时存活检测使用的默认超时
+pub const DEFAULT_LIVENESS_TIMEOUT_MS: u64 = 30_000;
+
+// 停止订阅时等待接收任务与回调排空的最长时间
+pub const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_MS: u64 = 5_000;
+
 // 性能监控相关常量
 pub const DEFAULT_METRICS_WINDOW_SECONDS: u64 = 5;
 pub const DEFAULT_METRICS_PRINT_INTERVAL_SECONDS: u64 = 10;
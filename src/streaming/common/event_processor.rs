@@ -1,12 +1,14 @@
 use crate::common::AnyResult;
 use crate::streaming::common::MetricsEventType;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::{ParseError, ParseErrorCallback};
 use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
 use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
 use crate::streaming::event_parser::core::event_parser::EventParser;
 use crate::streaming::event_parser::{core::traits::DexEvent, Protocol};
 use crate::streaming::grpc::{EventPretty, MetricsManager};
 use crate::streaming::shred::TransactionWithSlot;
+use prost::Message;
 use solana_sdk::pubkey::Pubkey;
 use std::sync::Arc;
 
@@ -42,16 +44,26 @@ pub async fn process_grpc_transaction(
     event_type_filter: Option<&EventTypeFilter>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     bot_wallet: Option<Pubkey>,
+    parse_error_callback: Option<&ParseErrorCallback>,
 ) -> AnyResult<()> {
     match event_pretty {
         EventPretty::Account(account_pretty) => {
             MetricsManager::global().add_account_process_count();
 
-            let account_event = AccountEventParser::parse_account_event(
+            let account_event = match AccountEventParser::try_parse_account_event(
                 protocols,
                 account_pretty,
                 event_type_filter,
-            );
+            ) {
+                Ok(event) => event,
+                Err(parse_error) => {
+                    log::debug!("{parse_error}");
+                    if let Some(parse_error_callback) = parse_error_callback {
+                        parse_error_callback(*parse_error);
+                    }
+                    None
+                }
+            };
 
             if let Some(event) = account_event {
                 let processing_time_us = event.metadata().handle_us as f64;
@@ -68,10 +80,11 @@ pub async fn process_grpc_transaction(
             let recv_us = transaction_pretty.recv_us;
             let transaction_index = transaction_pretty.transaction_index;
             let grpc_tx = transaction_pretty.grpc_tx;
+            let data_len = if parse_error_callback.is_some() { grpc_tx.encoded_len() } else { 0 };
 
             let adapter_callback = create_metrics_callback(callback.clone());
 
-            EventParser::parse_grpc_transaction(
+            let result = EventParser::parse_grpc_transaction(
                 protocols,
                 event_type_filter,
                 grpc_tx,
@@ -83,7 +96,28 @@ pub async fn process_grpc_transaction(
                 transaction_index,
                 adapter_callback,
             )
-            .await?;
+            .await;
+            if let Err(e) = result {
+                // 已知指令解码失败时携带协议信息，与账户解析失败一样只上报不中断
+                if let Some(parse_error) = e.downcast_ref::<ParseError>() {
+                    log::debug!("{parse_error}");
+                    if let Some(parse_error_callback) = parse_error_callback {
+                        parse_error_callback(parse_error.clone());
+                    }
+                    return Ok(());
+                }
+                if let Some(parse_error_callback) = parse_error_callback {
+                    parse_error_callback(ParseError {
+                        protocol: None,
+                        slot,
+                        data_len,
+                        pubkey: None,
+                        signature: Some(signature),
+                        error: e.to_string(),
+                    });
+                }
+                return Err(e);
+            }
         }
         EventPretty::BlockMeta(block_meta_pretty) => {
             MetricsManager::global().add_block_meta_process_count();
@@ -116,6 +150,7 @@ pub async fn process_shred_transaction(
     event_type_filter: Option<&EventTypeFilter>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     bot_wallet: Option<Pubkey>,
+    parse_error_callback: Option<&ParseErrorCallback>,
 ) -> AnyResult<()> {
     MetricsManager::global().add_tx_process_count();
 
@@ -146,9 +181,18 @@ pub async fn process_shred_transaction(
         None,
         adapter_callback,
     )
-    .await?;
-
-    Ok(())
+    .await
+    .or_else(|e| match e.downcast_ref::<ParseError>() {
+        // 已知指令解码失败时与 gRPC 路径一样交给解析失败回调，不中断
+        Some(parse_error) => {
+            log::debug!("{parse_error}");
+            if let Some(parse_error_callback) = parse_error_callback {
+                parse_error_callback(parse_error.clone());
+            }
+            Ok(())
+        }
+        None => Err(e),
+    })
 }
 
 /// Update metrics for event processing (with optional latency check)
@@ -5,6 +5,11 @@ pub mod constants;
 pub mod subscription;
 pub mod event_processor;
 pub mod simd_utils;
+pub mod callback_pool;
+pub mod stream_metrics;
+pub mod backfill;
+pub mod slot_gap;
+pub mod prometheus;
 
 // 重新导出主要类型
 pub use config::*;
@@ -12,4 +17,9 @@ pub use metrics::*;
 pub use constants::*;
 pub use subscription::*;
 pub use event_processor::*;
-pub use simd_utils::*;
\ No newline at end of file
+pub use simd_utils::*;
+pub use callback_pool::*;
+pub use stream_metrics::*;
+pub use backfill::*;
+pub use slot_gap::*;
+pub use prometheus::*;
\ No newline at end of file
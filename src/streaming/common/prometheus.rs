@@ -0,0 +1,350 @@
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use super::stream_metrics::{StreamMetrics, StreamMetricsCollector};
+use crate::common::AnyResult;
+
+/// 默认的指标路径
+pub const DEFAULT_PROMETHEUS_PATH: &str = "/metrics";
+
+/// 请求头的最大长度
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// 读取请求头的超时时间
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+type RegisteredClients = Arc<RwLock<Vec<(String, Arc<StreamMetricsCollector>)>>>;
+
+/// 以 Prometheus 文本格式导出已注册客户端的流统计
+///
+/// 每次抓取时读取 `StreamMetricsCollector` 快照，接收路径上不做额外工作。
+#[derive(Clone)]
+pub struct PrometheusExporter {
+    path: String,
+    clients: RegisteredClients,
+}
+
+impl Default for PrometheusExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrometheusExporter {
+    pub fn new() -> Self {
+        Self {
+            path: DEFAULT_PROMETHEUS_PATH.to_string(),
+            clients: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// 设置指标路径（默认：/metrics）
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// 注册一个客户端的统计，`client` 作为指标的 `client` 标签，同名客户端会被替换
+    ///
+    /// 例如 `exporter.register("yellowstone", grpc.stream_metrics.clone())`
+    pub fn register(&self, client: impl Into<String>, collector: Arc<StreamMetricsCollector>) {
+        let client = client.into();
+        let mut clients = self.clients.write();
+        match clients.iter_mut().find(|(name, _)| *name == client) {
+            Some(entry) => entry.1 = collector,
+            None => clients.push((client, collector)),
+        }
+    }
+
+    /// 生成 Prometheus 文本格式的指标
+    pub fn render(&self) -> String {
+        let snapshots: Vec<_> = self
+            .clients
+            .read()
+            .iter()
+            .map(|(client, collector)| (client.clone(), collector.snapshot()))
+            .collect();
+
+        let mut out = String::new();
+        let mut family = |name: &str, kind: &str, help: &str, samples: Vec<(String, f64)>| {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            for (labels, value) in samples {
+                let _ = writeln!(out, "{name}{{{labels}}} {value}");
+            }
+        };
+        let per_client = |value: &dyn Fn(&StreamMetrics) -> f64| {
+            snapshots
+                .iter()
+                .map(|(client, metrics)| (labels(&[("client", client)]), value(metrics)))
+                .collect::<Vec<_>>()
+        };
+
+        family(
+            "solana_streamer_messages_received_total",
+            "counter",
+            "gRPC messages received",
+            per_client(&|m| m.messages_received as f64),
+        );
+        family(
+            "solana_streamer_bytes_received_total",
+            "counter",
+            "gRPC message bytes received",
+            per_client(&|m| m.bytes_received as f64),
+        );
+        family(
+            "solana_streamer_parse_failures_total",
+            "counter",
+            "Account or transaction parse failures",
+            per_client(&|m| m.parse_failures as f64),
+        );
+        family(
+            "solana_streamer_reconnects_total",
+            "counter",
+            "Successful gRPC reconnects",
+            per_client(&|m| m.reconnects as f64),
+        );
+        family(
+            "solana_streamer_uptime_seconds",
+            "gauge",
+            "Seconds since the client was created",
+            per_client(&|m| m.uptime.as_secs_f64()),
+        );
+        family(
+            "solana_streamer_events_total",
+            "counter",
+            "Parsed events by protocol",
+            snapshots
+                .iter()
+                .flat_map(|(client, metrics)| {
+                    metrics.events_by_protocol.iter().map(move |(protocol, count)| {
+                        let protocol = protocol.to_string();
+                        (labels(&[("client", client), ("protocol", &protocol)]), *count as f64)
+                    })
+                })
+                .collect(),
+        );
+        family(
+            "solana_streamer_events_by_type_total",
+            "counter",
+            "Parsed events by event type",
+            snapshots
+                .iter()
+                .flat_map(|(client, metrics)| {
+                    metrics.events_by_event_type.iter().map(move |(event_type, count)| {
+                        let event_type = event_type.to_string();
+                        (labels(&[("client", client), ("event_type", &event_type)]), *count as f64)
+                    })
+                })
+                .collect(),
+        );
+        out
+    }
+
+    /// 在 `addr` 上启动 HTTP 服务，返回服务任务句柄
+    pub async fn serve(&self, addr: SocketAddr) -> AnyResult<JoinHandle<()>> {
+        self.serve_listener(TcpListener::bind(addr).await?)
+    }
+
+    /// 在已绑定的 `listener` 上启动 HTTP 服务，返回服务任务句柄
+    pub fn serve_listener(&self, listener: TcpListener) -> AnyResult<JoinHandle<()>> {
+        log::info!(
+            "Prometheus exporter listening on http://{}{}",
+            listener.local_addr()?,
+            self.path
+        );
+        let exporter = self.clone();
+        Ok(tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let exporter = exporter.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = exporter.handle_connection(stream).await {
+                                log::debug!("Prometheus exporter connection error: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => log::error!("Prometheus exporter accept error: {e}"),
+                }
+            }
+        }))
+    }
+
+    async fn handle_connection(&self, mut stream: TcpStream) -> AnyResult<()> {
+        let head = tokio::time::timeout(REQUEST_READ_TIMEOUT, read_request_head(&mut stream))
+            .await
+            .map_err(|_| anyhow!("request not received within {REQUEST_READ_TIMEOUT:?}"))??;
+        let request = String::from_utf8_lossy(&head);
+        let target = request.split_whitespace().nth(1).unwrap_or_default();
+        let path = target.split('?').next().unwrap_or_default();
+
+        let response = if request.starts_with("GET ") && path == self.path {
+            let body = self.render();
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+        } else {
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+        };
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await?;
+        Ok(())
+    }
+}
+
+/// 读取到请求头结束（`\r\n\r\n`）为止，超过 `MAX_REQUEST_HEAD_BYTES` 时返回错误
+async fn read_request_head(stream: &mut TcpStream) -> AnyResult<Vec<u8>> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() >= MAX_REQUEST_HEAD_BYTES {
+            return Err(anyhow!("request head exceeds {MAX_REQUEST_HEAD_BYTES} bytes"));
+        }
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            return Err(anyhow!("connection closed before the request head ended"));
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    Ok(head)
+}
+
+/// 生成标签串，按文本格式要求转义标签值中的 `\`、`"` 和换行
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let mut out = String::new();
+    for (index, (name, value)) in pairs.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{name}=\"");
+        for c in value.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\n' => out.push_str("\\n"),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::BlockMetaEvent;
+    use crate::streaming::event_parser::DexEvent;
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(
+            labels(&[("client", "a\\b\"c\nd"), ("protocol", "PumpFun")]),
+            r#"client="a\\b\"c\nd",protocol="PumpFun""#
+        );
+    }
+
+    #[tokio::test]
+    async fn scrape_serves_registered_metrics() {
+        let collector = Arc::new(StreamMetricsCollector::new());
+        collector.record_message(128);
+        collector.record_event(&DexEvent::BlockMetaEvent(BlockMetaEvent::new(
+            1,
+            String::new(),
+            0,
+            0,
+        )));
+        let exporter = PrometheusExporter::new();
+        exporter.register("yellowstone", collector);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = exporter.serve_listener(listener).unwrap();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        server.abort();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        for name in [
+            "solana_streamer_messages_received_total",
+            "solana_streamer_bytes_received_total",
+            "solana_streamer_parse_failures_total",
+            "solana_streamer_reconnects_total",
+            "solana_streamer_uptime_seconds",
+            "solana_streamer_events_total",
+            "solana_streamer_events_by_type_total",
+        ] {
+            assert!(response.contains(&format!("# TYPE {name} ")), "missing {name}");
+        }
+        assert!(
+            response.contains(r#"solana_streamer_bytes_received_total{client="yellowstone"} 128"#)
+        );
+        assert!(response
+            .contains(r#"solana_streamer_events_total{client="yellowstone",protocol="Common"} 1"#));
+        assert!(response.contains(
+            r#"solana_streamer_events_by_type_total{client="yellowstone",event_type="BlockMeta"} 1"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn request_head_split_across_reads_is_served() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = PrometheusExporter::new().serve_listener(listener).unwrap();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\n").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        stream.write_all(b"Host: localhost\r\n\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        server.abort();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[tokio::test]
+    async fn oversized_request_head_is_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = PrometheusExporter::new().serve_listener(listener).unwrap();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let mut request = b"GET /metrics HTTP/1.1\r\nX-Padding: ".to_vec();
+        request.resize(MAX_REQUEST_HEAD_BYTES * 2, b'a');
+        let _ = stream.write_all(&request).await;
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response).await;
+        server.abort();
+
+        assert!(response.is_empty());
+    }
+
+    #[test]
+    fn register_replaces_a_client_with_the_same_name() {
+        let exporter = PrometheusExporter::new();
+        exporter.register("yellowstone", Arc::new(StreamMetricsCollector::new()));
+        let collector = Arc::new(StreamMetricsCollector::new());
+        collector.record_message(64);
+        exporter.register("yellowstone", collector);
+
+        let rendered = exporter.render();
+        assert_eq!(rendered.matches(r#"bytes_received_total{client="yellowstone"}"#).count(), 1);
+        assert!(
+            rendered.contains(r#"solana_streamer_bytes_received_total{client="yellowstone"} 64"#)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// 两个相邻 slot 之间的缺口
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotGap {
+    /// 缺口前最后收到的 slot
+    pub from: u64,
+    /// 缺口后收到的 slot
+    pub to: u64,
+    /// 缺失的 slot 数
+    pub missed: u64,
+}
+
+/// 缺口回调
+pub type SlotGapCallback = Arc<dyn Fn(SlotGap) + Send + Sync>;
+
+/// Slot 缺口检测
+///
+/// 记录单调递增的 slot，跳跃大于 1 时通过回调上报。leader 跳过的 slot 同样会表现为缺口，
+/// 因此缺口只是丢失事件的近似指标。
+pub struct SlotGapDetector {
+    last_slot: AtomicU64,
+    callback: SlotGapCallback,
+}
+
+impl SlotGapDetector {
+    pub fn new(callback: SlotGapCallback) -> Self {
+        Self { last_slot: AtomicU64::new(0), callback }
+    }
+
+    /// 记录收到的 slot，出现缺口时调用回调并返回缺口
+    pub fn record(&self, slot: u64) -> Option<SlotGap> {
+        let previous = self.last_slot.fetch_max(slot, Ordering::Relaxed);
+        if previous == 0 || slot <= previous + 1 {
+            return None;
+        }
+        let gap = SlotGap { from: previous, to: slot, missed: slot - previous - 1 };
+        (self.callback)(gap);
+        Some(gap)
+    }
+
+    /// 最近记录的 slot
+    pub fn last_slot(&self) -> u64 {
+        self.last_slot.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    #[test]
+    fn reports_missed_slots_between_records() {
+        let gaps = Arc::new(Mutex::new(Vec::new()));
+        let sink = gaps.clone();
+        let detector = SlotGapDetector::new(Arc::new(move |gap| sink.lock().push(gap)));
+
+        assert_eq!(detector.record(100), None);
+        assert_eq!(detector.record(101), None);
+        let gap = SlotGap { from: 101, to: 105, missed: 3 };
+        assert_eq!(detector.record(105), Some(gap));
+        // 迟到或重复的 slot 不产生缺口
+        assert_eq!(detector.record(103), None);
+        assert_eq!(detector.record(105), None);
+
+        assert_eq!(*gaps.lock(), vec![gap]);
+        assert_eq!(detector.last_slot(), 105);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::streaming::event_parser::common::{EventType, ProtocolType};
+use crate::streaming::event_parser::DexEvent;
+
+/// 单个客户端的事件统计快照
+#[derive(Debug, Clone, Default)]
+pub struct StreamMetrics {
+    /// 自客户端创建以来的运行时长
+    pub uptime: Duration,
+    /// 收到的 gRPC 消息数
+    pub messages_received: u64,
+    /// 收到的 gRPC 消息字节数（protobuf 编码长度）
+    pub bytes_received: u64,
+    /// 解析失败次数
+    pub parse_failures: u64,
+    /// 成功重连次数
+    pub reconnects: u64,
+    /// 解析出的事件总数
+    pub events_total: u64,
+    /// 按协议统计的事件数
+    pub events_by_protocol: HashMap<ProtocolType, u64>,
+    /// 按事件类型统计的事件数
+    pub events_by_event_type: HashMap<EventType, u64>,
+}
+
+/// 基于原子计数的事件统计，读取快照不会阻塞接收路径
+#[derive(Debug)]
+pub struct StreamMetricsCollector {
+    started_at: Instant,
+    messages_received: AtomicU64,
+    bytes_received: AtomicU64,
+    parse_failures: AtomicU64,
+    reconnects: AtomicU64,
+    events_total: AtomicU64,
+    events_by_protocol: DashMap<ProtocolType, AtomicU64>,
+    events_by_event_type: DashMap<EventType, AtomicU64>,
+}
+
+impl Default for StreamMetricsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamMetricsCollector {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            messages_received: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            parse_failures: AtomicU64::new(0),
+            reconnects: AtomicU64::new(0),
+            events_total: AtomicU64::new(0),
+            events_by_protocol: DashMap::new(),
+            events_by_event_type: DashMap::new(),
+        }
+    }
+
+    /// 记录一条收到的消息
+    #[inline]
+    pub fn record_message(&self, bytes: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// 记录一次解析失败
+    #[inline]
+    pub fn record_parse_failure(&self) {
+        self.parse_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 记录一次成功的重连
+    #[inline]
+    pub fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 记录一个解析出的事件
+    #[inline]
+    pub fn record_event(&self, event: &DexEvent) {
+        let metadata = event.metadata();
+        self.events_total.fetch_add(1, Ordering::Relaxed);
+        increment(&self.events_by_protocol, &metadata.protocol);
+        increment(&self.events_by_event_type, &metadata.event_type);
+    }
+
+    /// 获取统计快照
+    pub fn snapshot(&self) -> StreamMetrics {
+        StreamMetrics {
+            uptime: self.started_at.elapsed(),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            parse_failures: self.parse_failures.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            events_total: self.events_total.load(Ordering::Relaxed),
+            events_by_protocol: collect(&self.events_by_protocol),
+            events_by_event_type: collect(&self.events_by_event_type),
+        }
+    }
+}
+
+#[inline]
+fn increment<K>(map: &DashMap<K, AtomicU64>, key: &K)
+where
+    K: Eq + std::hash::Hash + Clone,
+{
+    if let Some(counter) = map.get(key) {
+        counter.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    map.entry(key.clone()).or_default().fetch_add(1, Ordering::Relaxed);
+}
+
+fn collect<K>(map: &DashMap<K, AtomicU64>) -> HashMap<K, u64>
+where
+    K: Eq + std::hash::Hash + Clone,
+{
+    map.iter().map(|entry| (entry.key().clone(), entry.value().load(Ordering::Relaxed))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::EventMetadata;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+    use crate::streaming::event_parser::protocols::BlockMetaEvent;
+
+    fn pumpfun_trade(event_type: EventType) -> DexEvent {
+        DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+            metadata: EventMetadata {
+                protocol: ProtocolType::PumpFun,
+                event_type,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn snapshot_counts_events_by_protocol_and_type() {
+        let collector = StreamMetricsCollector::new();
+        collector.record_event(&pumpfun_trade(EventType::PumpFunBuy));
+        collector.record_event(&pumpfun_trade(EventType::PumpFunBuy));
+        collector.record_event(&pumpfun_trade(EventType::PumpFunSell));
+        collector.record_event(&DexEvent::BlockMetaEvent(BlockMetaEvent::new(
+            1,
+            String::new(),
+            0,
+            0,
+        )));
+
+        let snapshot = collector.snapshot();
+        assert_eq!(snapshot.events_total, 4);
+        assert_eq!(snapshot.events_by_protocol.len(), 2);
+        assert_eq!(snapshot.events_by_protocol[&ProtocolType::PumpFun], 3);
+        assert_eq!(snapshot.events_by_protocol[&ProtocolType::Common], 1);
+        assert_eq!(snapshot.events_by_event_type.len(), 3);
+        assert_eq!(snapshot.events_by_event_type[&EventType::PumpFunBuy], 2);
+        assert_eq!(snapshot.events_by_event_type[&EventType::PumpFunSell], 1);
+        assert_eq!(snapshot.events_by_event_type[&EventType::BlockMeta], 1);
+    }
+}
@@ -1,10 +1,15 @@
+use std::time::Duration;
+
 use tokio::task::JoinHandle;
 
+use super::callback_pool::CallbackWorkers;
+
 /// Subscription handle for managing and stopping subscriptions
 pub struct SubscriptionHandle {
     stream_handle: JoinHandle<()>,
     event_handle: Option<JoinHandle<()>>,
     metrics_handle: Option<JoinHandle<()>>,
+    callback_workers: Option<CallbackWorkers>,
 }
 
 impl SubscriptionHandle {
@@ -14,7 +19,30 @@ impl SubscriptionHandle {
         event_handle: Option<JoinHandle<()>>,
         metrics_handle: Option<JoinHandle<()>>,
     ) -> Self {
-        Self { stream_handle, event_handle, metrics_handle }
+        Self { stream_handle, event_handle, metrics_handle, callback_workers: None }
+    }
+
+    /// Attach the callback pool whose workers are stopped together with the subscription
+    pub fn with_callback_workers(mut self, callback_workers: Option<CallbackWorkers>) -> Self {
+        self.callback_workers = callback_workers;
+        self
+    }
+
+    /// Wait for the stream and event tasks to finish on their own, aborting them after `timeout`
+    pub async fn finish_streams(&mut self, timeout: Duration) {
+        let streams = async {
+            let _ = (&mut self.stream_handle).await;
+            if let Some(handle) = self.event_handle.as_mut() {
+                let _ = handle.await;
+            }
+        };
+        if tokio::time::timeout(timeout, streams).await.is_err() {
+            log::warn!("Stream tasks did not finish within {timeout:?}, aborting");
+            self.stream_handle.abort();
+            if let Some(handle) = &self.event_handle {
+                handle.abort();
+            }
+        }
     }
 
     /// Stop subscription and abort all related tasks
@@ -26,16 +54,30 @@ impl SubscriptionHandle {
         if let Some(handle) = self.metrics_handle {
             handle.abort();
         }
+        if let Some((_, worker_handles)) = self.callback_workers {
+            for handle in worker_handles {
+                handle.abort();
+            }
+        }
     }
 
     /// Asynchronously wait for all tasks to complete
+    ///
+    /// Once the stream tasks end, the callback pool is closed so its workers exit after the
+    /// queued events, and the metrics task, which never ends on its own, is aborted.
     pub async fn join(self) -> Result<(), tokio::task::JoinError> {
         let _ = self.stream_handle.await;
         if let Some(handle) = self.event_handle {
             let _ = handle.await;
         }
         if let Some(handle) = self.metrics_handle {
-            let _ = handle.await;
+            handle.abort();
+        }
+        if let Some((pool, worker_handles)) = self.callback_workers {
+            pool.close();
+            for handle in worker_handles {
+                let _ = handle.await;
+            }
         }
         Ok(())
     }
@@ -2,23 +2,107 @@ use crate::streaming::event_parser::common::{
     types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
 };
 
+/// 事件类型过滤器
+///
+/// `include` 非空时只保留其中的类型，随后再去掉 `exclude` 中的类型。
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct EventTypeFilter {
     pub include: Vec<EventType>,
+    pub exclude: Vec<EventType>,
 }
 
 impl EventTypeFilter {
+    /// 保留除 `exclude` 以外的所有事件类型
+    pub fn all_except(exclude: Vec<EventType>) -> Self {
+        Self { include: vec![], exclude }
+    }
+
+    /// 该事件类型是否通过过滤
+    pub fn accepts(&self, event_type: &EventType) -> bool {
+        (self.include.is_empty() || self.include.contains(event_type))
+            && !self.exclude.contains(event_type)
+    }
+
     pub fn include_transaction_event(&self) -> bool {
-        self.include
-            .iter()
-            .any(|event| !ACCOUNT_EVENT_TYPES.contains(event) && !BLOCK_EVENT_TYPES.contains(event))
+        if self.include.is_empty() {
+            return true;
+        }
+        self.include.iter().any(|event| {
+            !ACCOUNT_EVENT_TYPES.contains(event)
+                && !BLOCK_EVENT_TYPES.contains(event)
+                && !self.exclude.contains(event)
+        })
     }
 
     pub fn include_account_event(&self) -> bool {
-        self.include.iter().any(|event| ACCOUNT_EVENT_TYPES.contains(event))
+        self.includes_any(ACCOUNT_EVENT_TYPES)
     }
 
     pub fn include_block_event(&self) -> bool {
-        self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
+        self.includes_any(BLOCK_EVENT_TYPES)
+    }
+
+    fn includes_any(&self, event_types: &[EventType]) -> bool {
+        event_types.iter().any(|event| self.accepts(event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn include_only_keeps_listed_types() {
+        let filter = EventTypeFilter { include: vec![EventType::PumpFunBuy], exclude: vec![] };
+
+        assert!(filter.accepts(&EventType::PumpFunBuy));
+        assert!(!filter.accepts(&EventType::PumpFunSell));
+        assert!(filter.include_transaction_event());
+        assert!(!filter.include_account_event());
+        assert!(!filter.include_block_event());
+    }
+
+    #[test]
+    fn exclude_only_keeps_everything_else() {
+        let filter = EventTypeFilter::all_except(vec![EventType::BlockMeta]);
+
+        assert!(filter.accepts(&EventType::PumpFunBuy));
+        assert!(filter.accepts(&EventType::AccountMeteoraDlmmPoolState));
+        assert!(!filter.accepts(&EventType::BlockMeta));
+        assert!(filter.include_transaction_event());
+        assert!(filter.include_account_event());
+        assert!(!filter.include_block_event());
+    }
+
+    #[test]
+    fn exclude_applies_after_include() {
+        let filter = EventTypeFilter {
+            include: vec![EventType::PumpFunBuy, EventType::PumpFunSell, EventType::BlockMeta],
+            exclude: vec![EventType::PumpFunSell, EventType::BlockMeta],
+        };
+
+        assert!(filter.accepts(&EventType::PumpFunBuy));
+        assert!(!filter.accepts(&EventType::PumpFunSell));
+        assert!(!filter.accepts(&EventType::BlockMeta));
+        assert!(!filter.accepts(&EventType::PumpFunCreateToken));
+        assert!(filter.include_transaction_event());
+        assert!(!filter.include_block_event());
+
+        // include 中的交易类型全部被排除时不再订阅交易
+        let filter = EventTypeFilter {
+            include: vec![EventType::PumpFunSell],
+            exclude: vec![EventType::PumpFunSell],
+        };
+        assert!(!filter.include_transaction_event());
+    }
+
+    #[test]
+    fn empty_filter_accepts_everything() {
+        let filter = EventTypeFilter::default();
+
+        assert!(filter.accepts(&EventType::PumpFunBuy));
+        assert!(filter.include_transaction_event());
+        assert!(filter.include_account_event());
+        assert!(filter.include_block_event());
     }
 }
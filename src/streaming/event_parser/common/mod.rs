@@ -1,6 +1,8 @@
 pub mod filter;
 pub mod high_performance_clock;
+pub mod parse_error;
 pub mod types;
 pub mod utils;
+pub use parse_error::*;
 pub use types::*;
 pub use utils::*;
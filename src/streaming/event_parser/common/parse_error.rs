@@ -0,0 +1,83 @@
+use std::fmt;
+use std::sync::Arc;
+
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+use crate::streaming::event_parser::{DexEvent, Protocol};
+
+/// 解析失败回调
+pub type ParseErrorCallback = Arc<dyn Fn(ParseError) + Send + Sync>;
+
+/// 事件解析失败的信息，用于发现协议升级导致的布局变化
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    /// 推测的协议（按账户 owner 或失败位置判断），无法判断时为 None
+    pub protocol: Option<Protocol>,
+    /// 所在 slot
+    pub slot: u64,
+    /// 原始数据长度（账户为 data 长度，指令为指令数据长度，交易为 protobuf 编码长度）
+    pub data_len: usize,
+    /// 账户地址，交易解析失败时为 None
+    pub pubkey: Option<Pubkey>,
+    /// 交易签名，账户解析失败时为 None
+    pub signature: Option<Signature>,
+    /// 底层错误
+    pub error: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.protocol {
+            Some(protocol) => write!(f, "failed to parse {protocol} data")?,
+            None => write!(f, "failed to parse data")?,
+        }
+        write!(f, " ({} bytes) at slot {}: {}", self.data_len, self.slot, self.error)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 指令或账户的分发结果，区分未知判别器与已知判别器解码失败
+// 与原来返回的 Option<DexEvent> 大小相同，不额外装箱
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum DispatchResult {
+    /// 解析成功
+    Parsed(DexEvent),
+    /// 判别器不属于该协议已支持的类型
+    Unknown,
+    /// 判别器已知，但指令账户数少于该指令所需，不视为解析失败
+    MissingAccounts,
+    /// 判别器已知但数据解码失败，通常是协议升级改变了布局
+    DecodeFailed,
+}
+
+impl DispatchResult {
+    /// 指令账户数不少于 `min_accounts` 时执行解析，解析返回 None 视为解码失败
+    #[inline]
+    pub fn decode<F>(accounts: &[Pubkey], min_accounts: usize, parse: F) -> Self
+    where
+        F: FnOnce() -> Option<DexEvent>,
+    {
+        if accounts.len() < min_accounts {
+            return DispatchResult::MissingAccounts;
+        }
+        parse().into()
+    }
+
+    /// 取出解析成功的事件
+    #[inline]
+    pub fn into_event(self) -> Option<DexEvent> {
+        match self {
+            DispatchResult::Parsed(event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+impl From<Option<DexEvent>> for DispatchResult {
+    #[inline]
+    fn from(event: Option<DexEvent>) -> Self {
+        event.map_or(DispatchResult::DecodeFailed, DispatchResult::Parsed)
+    }
+}
@@ -41,7 +41,7 @@ lazy_static::lazy_static! {
 }
 
 #[derive(
-    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+    Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
 )]
 pub enum ProtocolType {
     #[default]
@@ -52,6 +52,8 @@ pub enum ProtocolType {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    MeteoraDlmm,
+    OrcaWhirlpool,
     Common,
 }
 
@@ -133,6 +135,8 @@ pub enum EventType {
     AccountRaydiumClmmTickArrayState,
     AccountRaydiumCpmmAmmConfig,
     AccountRaydiumCpmmPoolState,
+    AccountMeteoraDlmmPoolState,
+    AccountOrcaWhirlpoolPoolState,
 
     NonceAccount,
     TokenAccount,
@@ -159,11 +163,30 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumClmmTickArrayState,
     EventType::AccountRaydiumCpmmAmmConfig,
     EventType::AccountRaydiumCpmmPoolState,
+    EventType::AccountMeteoraDlmmPoolState,
+    EventType::AccountOrcaWhirlpoolPoolState,
     EventType::TokenAccount,
     EventType::NonceAccount,
 ];
 pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta];
 
+impl fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtocolType::PumpSwap => write!(f, "PumpSwap"),
+            ProtocolType::PumpFun => write!(f, "PumpFun"),
+            ProtocolType::Bonk => write!(f, "Bonk"),
+            ProtocolType::RaydiumCpmm => write!(f, "RaydiumCpmm"),
+            ProtocolType::RaydiumClmm => write!(f, "RaydiumClmm"),
+            ProtocolType::RaydiumAmmV4 => write!(f, "RaydiumAmmV4"),
+            ProtocolType::MeteoraDammV2 => write!(f, "MeteoraDammV2"),
+            ProtocolType::MeteoraDlmm => write!(f, "MeteoraDlmm"),
+            ProtocolType::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
+            ProtocolType::Common => write!(f, "Common"),
+        }
+    }
+}
+
 impl fmt::Display for EventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -232,6 +255,8 @@ impl fmt::Display for EventType {
             }
             EventType::AccountRaydiumCpmmAmmConfig => write!(f, "AccountRaydiumCpmmAmmConfig"),
             EventType::AccountRaydiumCpmmPoolState => write!(f, "AccountRaydiumCpmmPoolState"),
+            EventType::AccountMeteoraDlmmPoolState => write!(f, "AccountMeteoraDlmmPoolState"),
+            EventType::AccountOrcaWhirlpoolPoolState => write!(f, "AccountOrcaWhirlpoolPoolState"),
             EventType::TokenAccount => write!(f, "TokenAccount"),
             EventType::NonceAccount => write!(f, "NonceAccount"),
             EventType::BlockMeta => write!(f, "BlockMeta"),
@@ -1,6 +1,8 @@
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::elapsed_micros_since;
-use crate::streaming::event_parser::common::{EventMetadata, EventType, ProtocolType};
+use crate::streaming::event_parser::common::{
+    DispatchResult, EventMetadata, EventType, ParseError, ProtocolType,
+};
 use crate::streaming::event_parser::core::traits::DexEvent;
 use crate::streaming::event_parser::Protocol;
 use crate::streaming::grpc::AccountPretty;
@@ -61,6 +63,15 @@ impl AccountEventParser {
         account: AccountPretty,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<DexEvent> {
+        Self::try_parse_account_event(protocols, account, event_type_filter).unwrap_or(None)
+    }
+
+    /// 与 `parse_account_event` 相同，但已知账户类型解码失败时返回 `ParseError`
+    pub fn try_parse_account_event(
+        protocols: &[Protocol],
+        account: AccountPretty,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> Result<Option<DexEvent>, Box<ParseError>> {
         use crate::streaming::event_parser::core::dispatcher::EventDispatcher;
 
         // 1. 尝试从账户 discriminator 解析（协议特定账户）
@@ -84,21 +95,33 @@ impl AccountEventParser {
                     };
 
                     // 使用 dispatcher 解析
-                    if let Some(event) = EventDispatcher::dispatch_account(
-                        protocol,
+                    match EventDispatcher::dispatch_account(
+                        protocol.clone(),
                         discriminator,
                         &account,
                         metadata,
                     ) {
-                        // 应用事件类型过滤
-                        if let Some(filter) = event_type_filter {
-                            if filter.include.contains(&event.metadata().event_type) {
-                                return Some(event);
+                        DispatchResult::Parsed(event) => {
+                            // 应用事件类型过滤，不匹配时继续尝试其他解析方式
+                            if event_type_filter
+                                .is_none_or(|filter| filter.accepts(&event.metadata().event_type))
+                            {
+                                return Ok(Some(event));
                             }
-                            // 不匹配过滤器，继续尝试其他解析方式
-                        } else {
-                            return Some(event);
                         }
+                        DispatchResult::DecodeFailed => {
+                            // discriminator 已知但解码失败，通常是协议升级改变了账户布局
+                            return Err(Box::new(ParseError {
+                                protocol: Some(protocol),
+                                slot: account.slot,
+                                data_len: account.data.len(),
+                                pubkey: Some(account.pubkey),
+                                signature: None,
+                                error: "account data does not match the expected layout"
+                                    .to_string(),
+                            }));
+                        }
+                        DispatchResult::Unknown | DispatchResult::MissingAccounts => {}
                     }
                 }
             }
@@ -120,26 +143,26 @@ impl AccountEventParser {
         // 尝试解析 Nonce 账户
         if let Some(event) = Self::parse_nonce_account_event(&account, metadata.clone()) {
             if let Some(filter) = event_type_filter {
-                if filter.include.contains(&event.metadata().event_type) {
-                    return Some(event);
+                if filter.accepts(&event.metadata().event_type) {
+                    return Ok(Some(event));
                 }
             } else {
-                return Some(event);
+                return Ok(Some(event));
             }
         }
 
         // 尝试解析 Token 账户
         if let Some(event) = Self::parse_token_account_event(&account, metadata) {
             if let Some(filter) = event_type_filter {
-                if filter.include.contains(&event.metadata().event_type) {
-                    return Some(event);
+                if filter.accepts(&event.metadata().event_type) {
+                    return Ok(Some(event));
                 }
             } else {
-                return Some(event);
+                return Ok(Some(event));
             }
         }
 
-        None
+        Ok(None)
     }
 
     pub fn parse_token_account_event(
@@ -240,3 +263,39 @@ impl AccountEventParser {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::meteora_dlmm::{
+        discriminators, parser::METEORA_DLMM_PROGRAM_ID,
+    };
+
+    #[test]
+    fn truncated_known_account_reports_protocol() {
+        let mut data = discriminators::LB_PAIR.to_vec();
+        data.extend_from_slice(&[0u8; 16]);
+        let account = AccountPretty {
+            slot: 42,
+            pubkey: Pubkey::new_unique(),
+            owner: METEORA_DLMM_PROGRAM_ID,
+            data,
+            ..Default::default()
+        };
+
+        let parse_error = AccountEventParser::try_parse_account_event(
+            &[Protocol::MeteoraDlmm],
+            account.clone(),
+            None,
+        )
+        .expect_err("truncated LbPair data should be reported");
+        assert_eq!(parse_error.protocol, Some(Protocol::MeteoraDlmm));
+        assert_eq!(parse_error.slot, 42);
+        assert_eq!(parse_error.data_len, 24);
+        assert_eq!(parse_error.pubkey, Some(account.pubkey));
+
+        // 未请求的协议不解析，也不上报
+        assert!(AccountEventParser::try_parse_account_event(&[Protocol::PumpFun], account, None)
+            .is_ok());
+    }
+}
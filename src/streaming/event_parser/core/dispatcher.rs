@@ -8,12 +8,14 @@
 //! - **可测试性**: 每个函数都可以独立测试
 
 use crate::streaming::event_parser::{
-    common::EventMetadata,
+    common::{DispatchResult, EventMetadata},
     core::common_event_parser::{CommonEventParser, COMPUTE_BUDGET_PROGRAM_ID},
     protocols::{
-        bonk::parser as bonk, meteora_damm_v2::parser as meteora_damm_v2, pumpfun::parser as pumpfun,
-        pumpswap::parser as pumpswap, raydium_amm_v4::parser as raydium_amm_v4,
-        raydium_clmm::parser as raydium_clmm, raydium_cpmm::parser as raydium_cpmm,
+        bonk::parser as bonk, meteora_damm_v2::parser as meteora_damm_v2,
+        meteora_dlmm::parser as meteora_dlmm, orca_whirlpool::parser as orca_whirlpool,
+        pumpfun::parser as pumpfun, pumpswap::parser as pumpswap,
+        raydium_amm_v4::parser as raydium_amm_v4, raydium_clmm::parser as raydium_clmm,
+        raydium_cpmm::parser as raydium_cpmm,
     },
     DexEvent, Protocol,
 };
@@ -35,7 +37,8 @@ impl EventDispatcher {
     /// - `metadata`: 事件元数据
     ///
     /// # 返回
-    /// 解析成功返回 `Some(DexEvent)`，否则返回 `None`
+    /// 解析成功返回 `DispatchResult::Parsed`；判别器未知返回 `Unknown`，
+    /// 账户数不足返回 `MissingAccounts`，已知判别器解码失败返回 `DecodeFailed`
     #[inline]
     pub fn dispatch_instruction(
         protocol: Protocol,
@@ -43,7 +46,7 @@ impl EventDispatcher {
         instruction_data: &[u8],
         accounts: &[Pubkey],
         mut metadata: EventMetadata,
-    ) -> Option<DexEvent> {
+    ) -> DispatchResult {
         // 根据协议类型设置 metadata.protocol
         use crate::streaming::event_parser::common::ProtocolType;
         metadata.protocol = match protocol {
@@ -54,6 +57,8 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
         };
 
         match protocol {
@@ -99,6 +104,10 @@ impl EventDispatcher {
                 accounts,
                 metadata,
             ),
+            Protocol::MeteoraDlmm | Protocol::OrcaWhirlpool => {
+                // Meteora DLMM / Orca Whirlpool 目前只解析账户数据
+                DispatchResult::Unknown
+            }
         }
     }
 
@@ -129,6 +138,8 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
         };
 
         match protocol {
@@ -167,6 +178,10 @@ impl EventDispatcher {
                 inner_instruction_data,
                 metadata,
             ),
+            Protocol::MeteoraDlmm | Protocol::OrcaWhirlpool => {
+                // Meteora DLMM / Orca Whirlpool 目前只解析账户数据，返回 None
+                None
+            }
         }
     }
 
@@ -187,6 +202,10 @@ impl EventDispatcher {
             Some(Protocol::RaydiumAmmV4)
         } else if program_id == &meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID {
             Some(Protocol::MeteoraDammV2)
+        } else if program_id == &meteora_dlmm::METEORA_DLMM_PROGRAM_ID {
+            Some(Protocol::MeteoraDlmm)
+        } else if program_id == &orca_whirlpool::ORCA_WHIRLPOOL_PROGRAM_ID {
+            Some(Protocol::OrcaWhirlpool)
         } else {
             None
         }
@@ -225,6 +244,8 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID,
             Protocol::RaydiumAmmV4 => raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID,
             Protocol::MeteoraDammV2 => meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID,
+            Protocol::MeteoraDlmm => meteora_dlmm::METEORA_DLMM_PROGRAM_ID,
+            Protocol::OrcaWhirlpool => orca_whirlpool::ORCA_WHIRLPOOL_PROGRAM_ID,
         }
     }
 
@@ -244,13 +265,14 @@ impl EventDispatcher {
     /// - `metadata`: 事件元数据
     ///
     /// # 返回
-    /// 解析成功返回 `Some(DexEvent)`，否则返回 `None`
+    /// 解析成功返回 `DispatchResult::Parsed`；判别器未知返回 `Unknown`，
+    /// 已知判别器解码失败返回 `DecodeFailed`
     pub fn dispatch_account(
         protocol: Protocol,
         discriminator: &[u8],
         account: &crate::streaming::grpc::AccountPretty,
         mut metadata: crate::streaming::event_parser::common::EventMetadata,
-    ) -> Option<DexEvent> {
+    ) -> DispatchResult {
         // 根据协议类型设置 metadata.protocol
         use crate::streaming::event_parser::common::ProtocolType;
         metadata.protocol = match protocol {
@@ -261,6 +283,8 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
         };
 
         match protocol {
@@ -281,8 +305,14 @@ impl EventDispatcher {
                 raydium_amm_v4::parse_raydium_amm_v4_account_data(discriminator, account, metadata)
             }
             Protocol::MeteoraDammV2 => {
-                // Meteora DAMM 目前不需要解析账户数据，返回 None
-                None
+                // Meteora DAMM 目前不需要解析账户数据
+                DispatchResult::Unknown
+            }
+            Protocol::MeteoraDlmm => {
+                meteora_dlmm::parse_meteora_dlmm_account_data(discriminator, account, metadata)
+            }
+            Protocol::OrcaWhirlpool => {
+                orca_whirlpool::parse_orca_whirlpool_account_data(discriminator, account, metadata)
             }
         }
     }
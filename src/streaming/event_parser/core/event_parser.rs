@@ -1,6 +1,6 @@
 use crate::streaming::event_parser::{
     DexEvent, Protocol, common::{
-        EventMetadata, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
+        DispatchResult, EventMetadata, ParseError, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
     }, core::{
         dispatcher::EventDispatcher,
         global_state::{
@@ -15,7 +15,10 @@ use solana_sdk::{
     message::compiled_instruction::CompiledInstruction, pubkey::Pubkey, signature::Signature,
     transaction::VersionedTransaction,
 };
-use solana_transaction_status::InnerInstructions;
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+    InnerInstruction, InnerInstructions, UiInstruction,
+};
 use std::sync::Arc;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
@@ -129,6 +132,8 @@ impl EventParser {
         // 获取交易的指令和账户
         let compiled_instructions = transaction.message.instructions();
         let mut accounts: Vec<Pubkey> = accounts.to_vec();
+        // 单条指令解析失败不影响同一交易中其余指令，最后返回第一个错误
+        let mut first_error = None;
         // 检查交易中是否包含程序
         let has_program = accounts
             .iter()
@@ -147,7 +152,7 @@ impl EventParser {
                         if *max_idx as usize >= accounts.len() {
                             accounts.resize(*max_idx as usize + 1, Pubkey::default());
                         }
-                        Self::parse_events_from_instruction(
+                        let result = Self::parse_events_from_instruction(
                             protocols,
                             event_type_filter,
                             instruction,
@@ -162,14 +167,15 @@ impl EventParser {
                             transaction_index,
                             inner_instructions,
                             adapter_callback.clone(),
-                        )?;
+                        );
+                        first_error = first_error.or(result.err());
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
                         for (inner_index, inner_instruction) in
                             inner_instructions.instructions.iter().enumerate()
                         {
-                            Self::parse_events_from_instruction(
+                            let result = Self::parse_events_from_instruction(
                                 protocols,
                                 event_type_filter,
                                 &inner_instruction.instruction,
@@ -184,13 +190,97 @@ impl EventParser {
                                 transaction_index,
                                 Some(&inner_instructions),
                                 adapter_callback.clone(),
-                            )?;
+                            );
+                            first_error = first_error.or(result.err());
                         }
                     }
                 }
             }
         }
-        Ok(())
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Parse transaction returned by RPC `getTransaction`
+    ///
+    /// Decodes the transaction, converts compiled inner instructions and loaded addresses from
+    /// the status meta, and delegates to `parse_instruction_events_from_versioned_transaction`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_encoded_confirmed_transaction(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        transaction: EncodedConfirmedTransactionWithStatusMeta,
+        signature: Signature,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> anyhow::Result<()> {
+        let versioned_tx = transaction
+            .transaction
+            .transaction
+            .decode()
+            .ok_or_else(|| anyhow::anyhow!("Failed to decode transaction {signature}"))?;
+
+        let mut inner_instructions = Vec::new();
+        let mut loaded_accounts = Vec::new();
+        if let Some(meta) = &transaction.transaction.meta {
+            if let OptionSerializer::Some(ui_inner_instructions) = &meta.inner_instructions {
+                for ui_inner in ui_inner_instructions {
+                    let instructions = ui_inner
+                        .instructions
+                        .iter()
+                        .filter_map(|ui_instruction| match ui_instruction {
+                            UiInstruction::Compiled(ui_compiled) => {
+                                let data = solana_sdk::bs58::decode(&ui_compiled.data)
+                                    .into_vec()
+                                    .ok()?;
+                                Some(InnerInstruction {
+                                    instruction: CompiledInstruction {
+                                        program_id_index: ui_compiled.program_id_index,
+                                        accounts: ui_compiled.accounts.clone(),
+                                        data,
+                                    },
+                                    stack_height: ui_compiled.stack_height,
+                                })
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    inner_instructions.push(InnerInstructions { index: ui_inner.index, instructions });
+                }
+            }
+            if let OptionSerializer::Some(loaded_addresses) = &meta.loaded_addresses {
+                loaded_accounts.extend(
+                    loaded_addresses
+                        .writable
+                        .iter()
+                        .chain(loaded_addresses.readonly.iter())
+                        .filter_map(|s| s.parse::<Pubkey>().ok()),
+                );
+            }
+        }
+
+        let static_keys = versioned_tx.message.static_account_keys();
+        let mut accounts = Vec::with_capacity(static_keys.len() + loaded_accounts.len());
+        accounts.extend_from_slice(static_keys);
+        accounts.extend(loaded_accounts);
+
+        let block_time = transaction.block_time.map(|t| Timestamp { seconds: t, nanos: 0 });
+
+        Self::parse_instruction_events_from_versioned_transaction(
+            protocols,
+            event_type_filter,
+            &versioned_tx,
+            signature,
+            Some(transaction.slot),
+            block_time,
+            recv_us,
+            &accounts,
+            &inner_instructions,
+            bot_wallet,
+            None,
+            callback,
+        )
+        .await
     }
 
     // ================================================================================================
@@ -218,6 +308,8 @@ impl EventParser {
     ) -> anyhow::Result<()> {
         // 获取交易的指令和账户
         let mut accounts = accounts.to_vec();
+        // 单条指令解析失败不影响同一交易中其余指令，最后返回第一个错误
+        let mut first_error = None;
         // 检查交易中是否包含程序
         let has_program = accounts
             .iter()
@@ -236,7 +328,7 @@ impl EventParser {
                         accounts.resize(*max_idx as usize + 1, Pubkey::default());
                     }
                     if Self::should_handle(protocols, event_type_filter, &program_id) {
-                        Self::parse_events_from_grpc_instruction(
+                        let result = Self::parse_events_from_grpc_instruction(
                             protocols,
                             event_type_filter,
                             instruction,
@@ -251,7 +343,8 @@ impl EventParser {
                             transaction_index,
                             inner_instructions,
                             callback.clone(),
-                        )?;
+                        );
+                        first_error = first_error.or(result.err());
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
@@ -266,7 +359,7 @@ impl EventParser {
                                     accounts: inner_accounts.to_vec(),
                                     data: data.to_vec(),
                                 };
-                            Self::parse_events_from_grpc_instruction(
+                            let result = Self::parse_events_from_grpc_instruction(
                                 protocols,
                                 event_type_filter,
                                 &instruction,
@@ -281,13 +374,14 @@ impl EventParser {
                                 transaction_index,
                                 Some(&inner_instructions),
                                 callback.clone(),
-                            )?;
+                            );
+                            first_error = first_error.or(result.err());
                         }
                     }
                 }
             }
         }
-        Ok(())
+        first_error.map_or(Ok(()), Err)
     }
 
     /// Parse events from gRPC instruction
@@ -354,7 +448,9 @@ impl EventParser {
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                if Self::should_emit(event_type_filter, &event) {
+                    callback(&event);
+                }
             }
             return Ok(());
         }
@@ -384,8 +480,17 @@ impl EventParser {
             &account_pubkeys,
             metadata.clone(),
         ) {
-            Some(e) => e,
-            None => return Ok(()),
+            DispatchResult::Parsed(e) => e,
+            DispatchResult::DecodeFailed => {
+                return Err(Self::instruction_decode_error(
+                    protocol,
+                    &instruction.data,
+                    signature,
+                    slot,
+                ))
+            }
+            // 未知指令与账户数不足的指令直接跳过
+            DispatchResult::Unknown | DispatchResult::MissingAccounts => return Ok(()),
         };
 
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
@@ -462,8 +567,11 @@ impl EventParser {
 
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+        // 先经过 process_event 记录 dev 地址等状态，再应用事件类型过滤
         event = Self::process_event(event, bot_wallet);
-        callback(&event);
+        if Self::should_emit(event_type_filter, &event) {
+            callback(&event);
+        }
 
         Ok(())
     }
@@ -537,7 +645,9 @@ impl EventParser {
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                if Self::should_emit(event_type_filter, &event) {
+                    callback(&event);
+                }
             }
             return Ok(());
         }
@@ -567,8 +677,17 @@ impl EventParser {
             &account_pubkeys,
             metadata.clone(),
         ) {
-            Some(e) => e,
-            None => return Ok(()),
+            DispatchResult::Parsed(e) => e,
+            DispatchResult::DecodeFailed => {
+                return Err(Self::instruction_decode_error(
+                    protocol,
+                    &instruction.data,
+                    signature,
+                    slot,
+                ))
+            }
+            // 未知指令与账户数不足的指令直接跳过
+            DispatchResult::Unknown | DispatchResult::MissingAccounts => return Ok(()),
         };
 
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
@@ -645,8 +764,11 @@ impl EventParser {
 
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+        // 先经过 process_event 记录 dev 地址等状态，再应用事件类型过滤
         event = Self::process_event(event, bot_wallet);
-        callback(&event);
+        if Self::should_emit(event_type_filter, &event) {
+            callback(&event);
+        }
 
         Ok(())
     }
@@ -655,6 +777,32 @@ impl EventParser {
     // Helper Functions
     // ================================================================================================
 
+    /// Build the error for a known instruction discriminator that failed to decode
+    ///
+    /// A known discriminator whose data does not decode usually means the protocol changed its
+    /// instruction layout.
+    fn instruction_decode_error(
+        protocol: Protocol,
+        instruction_data: &[u8],
+        signature: Signature,
+        slot: u64,
+    ) -> anyhow::Error {
+        ParseError {
+            protocol: Some(protocol),
+            slot,
+            data_len: instruction_data.len(),
+            pubkey: None,
+            signature: Some(signature),
+            error: "instruction data does not match the expected layout".to_string(),
+        }
+        .into()
+    }
+
+    /// Check if a parsed event passes the event type filter
+    fn should_emit(event_type_filter: Option<&EventTypeFilter>, event: &DexEvent) -> bool {
+        event_type_filter.is_none_or(|filter| filter.accepts(&event.metadata().event_type))
+    }
+
     /// Check if instruction should be processed based on protocol filter
     ///
     /// Determines whether a program_id matches any of the protocols we're interested in.
@@ -752,3 +900,70 @@ impl EventParser {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::{
+        discriminators, parser::PUMPFUN_PROGRAM_ID,
+    };
+    use solana_sdk::message::{Message, VersionedMessage};
+
+    fn pumpfun_transaction(data: Vec<u8>, num_accounts: u8) -> VersionedTransaction {
+        let mut account_keys = vec![PUMPFUN_PROGRAM_ID];
+        account_keys.extend((0..num_accounts).map(|_| Pubkey::new_unique()));
+        VersionedTransaction {
+            signatures: vec![Signature::new_unique()],
+            message: VersionedMessage::Legacy(Message {
+                account_keys,
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 0,
+                    accounts: (1..=num_accounts).collect(),
+                    data,
+                }],
+                ..Default::default()
+            }),
+        }
+    }
+
+    async fn parse(transaction: &VersionedTransaction) -> anyhow::Result<()> {
+        EventParser::parse_instruction_events_from_versioned_transaction(
+            &[Protocol::PumpFun],
+            None,
+            transaction,
+            transaction.signatures[0],
+            Some(7),
+            None,
+            0,
+            transaction.message.static_account_keys(),
+            &[],
+            None,
+            None,
+            Arc::new(|_| {}),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn truncated_known_instruction_reports_protocol() {
+        let transaction = pumpfun_transaction(discriminators::BUY_IX.to_vec(), 16);
+        let error = parse(&transaction).await.expect_err("truncated buy should be reported");
+        let parse_error = error.downcast_ref::<ParseError>().expect("expected a ParseError");
+        assert_eq!(parse_error.protocol, Some(Protocol::PumpFun));
+        assert_eq!(parse_error.slot, 7);
+        assert_eq!(parse_error.signature, Some(transaction.signatures[0]));
+    }
+
+    #[tokio::test]
+    async fn known_instruction_with_too_few_accounts_is_skipped() {
+        let mut data = discriminators::BUY_IX.to_vec();
+        data.extend_from_slice(&[0u8; 16]);
+        let transaction = pumpfun_transaction(data, 15);
+        assert!(parse(&transaction).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unknown_instruction_is_skipped() {
+        let transaction = pumpfun_transaction(vec![0u8; 24], 16);
+        assert!(parse(&transaction).await.is_ok());
+    }
+}
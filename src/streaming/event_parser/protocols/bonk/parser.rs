@@ -1,7 +1,7 @@
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::{
-    common::{utils::*, EventMetadata, EventType},
+    common::{utils::*, DispatchResult, EventMetadata, EventType},
     protocols::bonk::{
         bonk_pool_create_event_log_decode, bonk_trade_event_log_decode, discriminators, AmmFeeOn,
         BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent, BonkPoolCreateEvent, BonkTradeEvent,
@@ -23,36 +23,36 @@ pub fn parse_bonk_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::BUY_EXACT_IN => {
+        discriminators::BUY_EXACT_IN => DispatchResult::decode(accounts, 18, || {
             parse_buy_exact_in_instruction(data, accounts, metadata)
-        }
-        discriminators::BUY_EXACT_OUT => {
+        }),
+        discriminators::BUY_EXACT_OUT => DispatchResult::decode(accounts, 18, || {
             parse_buy_exact_out_instruction(data, accounts, metadata)
-        }
-        discriminators::SELL_EXACT_IN => {
+        }),
+        discriminators::SELL_EXACT_IN => DispatchResult::decode(accounts, 18, || {
             parse_sell_exact_in_instruction(data, accounts, metadata)
-        }
-        discriminators::SELL_EXACT_OUT => {
+        }),
+        discriminators::SELL_EXACT_OUT => DispatchResult::decode(accounts, 18, || {
             parse_sell_exact_out_instruction(data, accounts, metadata)
-        }
-        discriminators::INITIALIZE => {
+        }),
+        discriminators::INITIALIZE => DispatchResult::decode(accounts, 10, || {
             parse_initialize_instruction(data, accounts, metadata)
-        }
-        discriminators::INITIALIZE_V2 => {
+        }),
+        discriminators::INITIALIZE_V2 => DispatchResult::decode(accounts, 10, || {
             parse_initialize_v2_instruction(data, accounts, metadata)
-        }
-        discriminators::INITIALIZE_WITH_TOKEN_2022 => {
+        }),
+        discriminators::INITIALIZE_WITH_TOKEN_2022 => DispatchResult::decode(accounts, 10, || {
             parse_initialize_with_token_2022_instruction(data, accounts, metadata)
-        }
-        discriminators::MIGRATE_TO_AMM => {
+        }),
+        discriminators::MIGRATE_TO_AMM => DispatchResult::decode(accounts, 32, || {
             parse_migrate_to_amm_instruction(data, accounts, metadata)
-        }
-        discriminators::MIGRATE_TO_CP_SWAP => {
+        }),
+        discriminators::MIGRATE_TO_CP_SWAP => DispatchResult::decode(accounts, 28, || {
             parse_migrate_to_cpswap_instruction(data, accounts, metadata)
-        }
-        _ => None,
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -82,18 +82,27 @@ pub fn parse_bonk_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::POOL_STATE_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::pool_state_parser(account, metadata)
+            crate::streaming::event_parser::protocols::bonk::types::pool_state_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::GLOBAL_CONFIG_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::global_config_parser(account, metadata)
+            crate::streaming::event_parser::protocols::bonk::types::global_config_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::PLATFORM_CONFIG_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::platform_config_parser(account, metadata)
+            crate::streaming::event_parser::protocols::bonk::types::platform_config_parser(
+                account, metadata,
+            )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -140,7 +149,7 @@ fn parse_buy_exact_in_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::BonkBuyExactIn;
 
-    if data.len() < 16 || accounts.len() < 18 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -180,7 +189,7 @@ fn parse_buy_exact_out_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::BonkBuyExactOut;
 
-    if data.len() < 16 || accounts.len() < 18 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -220,7 +229,7 @@ fn parse_sell_exact_in_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::BonkSellExactIn;
 
-    if data.len() < 16 || accounts.len() < 18 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -260,7 +269,7 @@ fn parse_sell_exact_out_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::BonkSellExactOut;
 
-    if data.len() < 16 || accounts.len() < 18 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
+    common::{DispatchResult, EventMetadata, EventType},
     protocols::meteora_damm_v2::{
         discriminators, meteora_damm_v2_initialize_pool_event_decode,
         meteora_damm_v2_swap_event_decode, MeteoraDammV2InitializeCustomizablePoolEvent,
@@ -22,20 +22,28 @@ pub fn parse_meteora_damm_v2_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::SWAP_IX => parse_swap_instruction(data, accounts, metadata),
-        discriminators::SWAP2_IX => parse_swap2_instruction(data, accounts, metadata),
-        discriminators::INITIALIZE_POOL_IX => {
+        discriminators::SWAP_IX => DispatchResult::decode(accounts, 14, || {
+            parse_swap_instruction(data, accounts, metadata)
+        }),
+        discriminators::SWAP2_IX => DispatchResult::decode(accounts, 13, || {
+            parse_swap2_instruction(data, accounts, metadata)
+        }),
+        discriminators::INITIALIZE_POOL_IX => DispatchResult::decode(accounts, 20, || {
             parse_initialize_pool_instruction(data, accounts, metadata)
-        }
+        }),
         discriminators::INITIALIZE_CUSTOMIZABLE_POOL_IX => {
-            parse_initialize_customizable_pool_instruction(data, accounts, metadata)
+            DispatchResult::decode(accounts, 19, || {
+                parse_initialize_customizable_pool_instruction(data, accounts, metadata)
+            })
         }
         discriminators::INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX => {
-            parse_initialize_pool_with_dynamic_config_instruction(data, accounts, metadata)
+            DispatchResult::decode(accounts, 21, || {
+                parse_initialize_pool_with_dynamic_config_instruction(data, accounts, metadata)
+            })
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -64,7 +72,7 @@ fn parse_swap_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::MeteoraDammV2Swap;
 
-    if data.len() < 16 || accounts.len() < 14 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -102,7 +110,7 @@ fn parse_swap2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::MeteoraDammV2Swap2;
 
-    if data.len() < 16 || accounts.len() < 13 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -151,10 +159,6 @@ fn parse_initialize_pool_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::MeteoraDammV2InitializePool;
 
-    if accounts.len() < 20 {
-        return None;
-    }
-
     // 解析 instruction data (不包含 discriminator，已被调用者移除)
     // 结构: liquidity (u128 = 16 bytes) + sqrt_price (u128 = 16 bytes) + activation_point (Option<u64> = 1 + 8 bytes)
     if data.len() < 33 {
@@ -215,10 +219,6 @@ fn parse_initialize_customizable_pool_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::MeteoraDammV2InitializeCustomizablePool;
 
-    if accounts.len() < 19 {
-        return None;
-    }
-
     // 解析 instruction data (不包含 discriminator)
     // 结构: PoolFeeParameters + sqrt_min_price + sqrt_max_price + has_alpha_vault + liquidity + sqrt_price + activation_type + collect_fee_mode + activation_point
     if data.len() < 99 {
@@ -322,10 +322,6 @@ fn parse_initialize_pool_with_dynamic_config_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::MeteoraDammV2InitializePoolWithDynamicConfig;
 
-    if accounts.len() < 21 {
-        return None;
-    }
-
     if data.len() < 99 {
         return None;
     }
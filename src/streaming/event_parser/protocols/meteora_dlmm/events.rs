@@ -0,0 +1,24 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_dlmm::types::LbPair;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 池状态
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmPoolStateAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool_state: LbPair,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 账号鉴别器
+    pub const LB_PAIR: &[u8] = &[33, 11, 49, 98, 181, 101, 177, 13];
+}
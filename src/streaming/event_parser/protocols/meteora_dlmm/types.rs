@@ -0,0 +1,215 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::meteora_dlmm::MeteoraDlmmPoolStateAccountEvent,
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct StaticParameters {
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub base_fee_power_factor: u8,
+    pub padding: [u8; 5],
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct VariableParameters {
+    pub volatility_accumulator: u32,
+    pub volatility_reference: u32,
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct ProtocolFee {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct RewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    pub bump_seed: [u8; 1],
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub creator_pool_on_off_control: u8,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub protocol_fee: ProtocolFee,
+    pub padding1: [u8; 32],
+    pub reward_infos: [RewardInfo; 2],
+    pub oracle: Pubkey,
+    pub bin_array_bitmap: [u64; 16],
+    pub last_updated_at: i64,
+    pub padding2: [u8; 32],
+    pub pre_activation_swap_address: Pubkey,
+    pub base_key: Pubkey,
+    pub activation_point: u64,
+    pub pre_activation_duration: u64,
+    pub padding3: [u8; 8],
+    pub padding4: u64,
+    pub creator: Pubkey,
+    pub token_mint_x_program_flag: u8,
+    pub token_mint_y_program_flag: u8,
+    pub reserved: [u8; 22],
+}
+
+pub const LB_PAIR_SIZE: usize = 896;
+
+pub fn lb_pair_decode(data: &[u8]) -> Option<LbPair> {
+    if data.len() < LB_PAIR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<LbPair>(&data[..LB_PAIR_SIZE]).ok()
+}
+
+pub fn lb_pair_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDlmmPoolState;
+
+    if account.data.len() < LB_PAIR_SIZE + 8 {
+        return None;
+    }
+    lb_pair_decode(&account.data[8..LB_PAIR_SIZE + 8]).map(|pool_state| {
+        DexEvent::MeteoraDlmmPoolStateAccountEvent(MeteoraDlmmPoolStateAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            pool_state,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::meteora_dlmm::discriminators;
+
+    /// 账户数据偏移（含 8 字节 discriminator）
+    const ACTIVE_ID_OFFSET: usize = 76;
+    const BIN_STEP_OFFSET: usize = 80;
+    const TOKEN_X_MINT_OFFSET: usize = 88;
+
+    fn lb_pair_account(active_id: i32, bin_step: u16, token_x_mint: Pubkey) -> Vec<u8> {
+        let mut data = vec![0u8; LB_PAIR_SIZE + 8];
+        data[..8].copy_from_slice(discriminators::LB_PAIR);
+        data[ACTIVE_ID_OFFSET..ACTIVE_ID_OFFSET + 4].copy_from_slice(&active_id.to_le_bytes());
+        data[BIN_STEP_OFFSET..BIN_STEP_OFFSET + 2].copy_from_slice(&bin_step.to_le_bytes());
+        data[TOKEN_X_MINT_OFFSET..TOKEN_X_MINT_OFFSET + 32].copy_from_slice(token_x_mint.as_ref());
+        data
+    }
+
+    #[test]
+    fn lb_pair_fields_land_at_on_chain_offsets() {
+        let token_x_mint = Pubkey::new_unique();
+        let account =
+            AccountPretty { data: lb_pair_account(-1234, 25, token_x_mint), ..Default::default() };
+
+        let Some(DexEvent::MeteoraDlmmPoolStateAccountEvent(event)) =
+            lb_pair_parser(&account, EventMetadata::default())
+        else {
+            panic!("expected a Meteora DLMM pool state event");
+        };
+        assert_eq!(event.metadata.event_type, EventType::AccountMeteoraDlmmPoolState);
+        assert_eq!(event.pool_state.active_id, -1234);
+        assert_eq!(event.pool_state.bin_step, 25);
+        assert_eq!(event.pool_state.token_x_mint, token_x_mint);
+    }
+
+    #[test]
+    fn lb_pair_rejects_short_data() {
+        assert!(lb_pair_decode(&[0u8; LB_PAIR_SIZE - 1]).is_none());
+
+        let mut data = lb_pair_account(1, 1, Pubkey::new_unique());
+        data.pop();
+        let account = AccountPretty { data, ..Default::default() };
+        assert!(lb_pair_parser(&account, EventMetadata::default()).is_none());
+    }
+
+    /// 主网 LbPair 账户导出（原始账户数据，含 discriminator）:
+    /// `solana account <LB_PAIR_ADDRESS> --output-file tests/fixtures/meteora_dlmm_lb_pair.bin`
+    const MAINNET_LB_PAIR_FIXTURE: &str = "tests/fixtures/meteora_dlmm_lb_pair.bin";
+
+    /// 解码后重新序列化必须与原始字节完全一致，确保每个字段都按链上顺序与宽度读取
+    fn assert_round_trip(data: &[u8]) -> LbPair {
+        assert_eq!(&data[..8], discriminators::LB_PAIR);
+        let pool_state = lb_pair_decode(&data[8..]).expect("LbPair should decode");
+        let encoded = borsh::to_vec(&pool_state).unwrap();
+        assert_eq!(encoded.len(), LB_PAIR_SIZE);
+        assert_eq!(encoded, &data[8..LB_PAIR_SIZE + 8]);
+        pool_state
+    }
+
+    #[test]
+    fn lb_pair_round_trips_every_byte() {
+        let mut data = discriminators::LB_PAIR.to_vec();
+        data.extend((0..LB_PAIR_SIZE).map(|i| (i * 31 + 7) as u8));
+        let pool_state = assert_round_trip(&data);
+        assert_eq!(
+            pool_state.active_id,
+            i32::from_le_bytes(data[ACTIVE_ID_OFFSET..ACTIVE_ID_OFFSET + 4].try_into().unwrap())
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a captured mainnet LbPair account at tests/fixtures/meteora_dlmm_lb_pair.bin"]
+    fn lb_pair_round_trips_mainnet_account() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(MAINNET_LB_PAIR_FIXTURE);
+        let data = std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let pool_state = assert_round_trip(&data);
+        assert_ne!(pool_state.bin_step, 0);
+        assert!(pool_state.parameters.min_bin_id <= pool_state.active_id);
+        assert!(pool_state.active_id <= pool_state.parameters.max_bin_id);
+        assert_ne!(pool_state.token_x_mint, Pubkey::default());
+        assert_ne!(pool_state.token_y_mint, Pubkey::default());
+    }
+}
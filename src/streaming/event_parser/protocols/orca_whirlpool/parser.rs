@@ -0,0 +1,28 @@
+use crate::streaming::event_parser::{
+    common::{DispatchResult, EventMetadata},
+    protocols::orca_whirlpool::discriminators,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Orca Whirlpool 程序ID
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// 解析 Orca Whirlpool 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_orca_whirlpool_account_data(
+    discriminator: &[u8],
+    account: &crate::streaming::grpc::AccountPretty,
+    metadata: EventMetadata,
+) -> DispatchResult {
+    match discriminator {
+        discriminators::WHIRLPOOL => {
+            crate::streaming::event_parser::protocols::orca_whirlpool::types::whirlpool_parser(
+                account, metadata,
+            )
+            .into()
+        }
+        _ => DispatchResult::Unknown,
+    }
+}
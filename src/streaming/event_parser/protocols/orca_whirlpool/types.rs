@@ -0,0 +1,170 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::orca_whirlpool::OrcaWhirlpoolPoolStateAccountEvent,
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub fee_tier_index_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+pub const WHIRLPOOL_SIZE: usize = 645;
+
+pub fn whirlpool_decode(data: &[u8]) -> Option<Whirlpool> {
+    if data.len() < WHIRLPOOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Whirlpool>(&data[..WHIRLPOOL_SIZE]).ok()
+}
+
+pub fn whirlpool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountOrcaWhirlpoolPoolState;
+
+    if account.data.len() < WHIRLPOOL_SIZE + 8 {
+        return None;
+    }
+    whirlpool_decode(&account.data[8..WHIRLPOOL_SIZE + 8]).map(|pool_state| {
+        DexEvent::OrcaWhirlpoolPoolStateAccountEvent(OrcaWhirlpoolPoolStateAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            pool_state,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::orca_whirlpool::discriminators;
+
+    /// 账户数据偏移（含 8 字节 discriminator）
+    const SQRT_PRICE_OFFSET: usize = 65;
+    const TICK_CURRENT_INDEX_OFFSET: usize = 81;
+    const TOKEN_MINT_A_OFFSET: usize = 101;
+
+    fn whirlpool_account(
+        sqrt_price: u128,
+        tick_current_index: i32,
+        token_mint_a: Pubkey,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; WHIRLPOOL_SIZE + 8];
+        data[..8].copy_from_slice(discriminators::WHIRLPOOL);
+        data[SQRT_PRICE_OFFSET..SQRT_PRICE_OFFSET + 16].copy_from_slice(&sqrt_price.to_le_bytes());
+        data[TICK_CURRENT_INDEX_OFFSET..TICK_CURRENT_INDEX_OFFSET + 4]
+            .copy_from_slice(&tick_current_index.to_le_bytes());
+        data[TOKEN_MINT_A_OFFSET..TOKEN_MINT_A_OFFSET + 32].copy_from_slice(token_mint_a.as_ref());
+        data
+    }
+
+    #[test]
+    fn whirlpool_fields_land_at_on_chain_offsets() {
+        let sqrt_price = 7_987_301_247_384_092_211_u128;
+        let token_mint_a = Pubkey::new_unique();
+        let account = AccountPretty {
+            data: whirlpool_account(sqrt_price, -20_123, token_mint_a),
+            ..Default::default()
+        };
+
+        let Some(DexEvent::OrcaWhirlpoolPoolStateAccountEvent(event)) =
+            whirlpool_parser(&account, EventMetadata::default())
+        else {
+            panic!("expected an Orca Whirlpool pool state event");
+        };
+        assert_eq!(event.metadata.event_type, EventType::AccountOrcaWhirlpoolPoolState);
+        assert_eq!(event.pool_state.sqrt_price, sqrt_price);
+        assert_eq!(event.pool_state.tick_current_index, -20_123);
+        assert_eq!(event.pool_state.token_mint_a, token_mint_a);
+    }
+
+    #[test]
+    fn whirlpool_rejects_short_data() {
+        assert!(whirlpool_decode(&[0u8; WHIRLPOOL_SIZE - 1]).is_none());
+
+        let mut data = whirlpool_account(1, 1, Pubkey::new_unique());
+        data.pop();
+        let account = AccountPretty { data, ..Default::default() };
+        assert!(whirlpool_parser(&account, EventMetadata::default()).is_none());
+    }
+
+    /// 主网 Whirlpool 账户导出（原始账户数据，含 discriminator）:
+    /// `solana account <WHIRLPOOL_ADDRESS> --output-file tests/fixtures/orca_whirlpool.bin`
+    const MAINNET_WHIRLPOOL_FIXTURE: &str = "tests/fixtures/orca_whirlpool.bin";
+
+    /// 解码后重新序列化必须与原始字节完全一致，确保每个字段都按链上顺序与宽度读取
+    fn assert_round_trip(data: &[u8]) -> Whirlpool {
+        assert_eq!(&data[..8], discriminators::WHIRLPOOL);
+        let pool_state = whirlpool_decode(&data[8..]).expect("Whirlpool should decode");
+        let encoded = borsh::to_vec(&pool_state).unwrap();
+        assert_eq!(encoded.len(), WHIRLPOOL_SIZE);
+        assert_eq!(encoded, &data[8..WHIRLPOOL_SIZE + 8]);
+        pool_state
+    }
+
+    #[test]
+    fn whirlpool_round_trips_every_byte() {
+        let mut data = discriminators::WHIRLPOOL.to_vec();
+        data.extend((0..WHIRLPOOL_SIZE).map(|i| (i * 31 + 7) as u8));
+        let pool_state = assert_round_trip(&data);
+        assert_eq!(
+            pool_state.sqrt_price,
+            u128::from_le_bytes(
+                data[SQRT_PRICE_OFFSET..SQRT_PRICE_OFFSET + 16].try_into().unwrap()
+            )
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a captured mainnet Whirlpool account at tests/fixtures/orca_whirlpool.bin"]
+    fn whirlpool_round_trips_mainnet_account() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(MAINNET_WHIRLPOOL_FIXTURE);
+        let data = std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let pool_state = assert_round_trip(&data);
+        assert_ne!(pool_state.tick_spacing, 0);
+        assert_ne!(pool_state.sqrt_price, 0);
+        assert_ne!(pool_state.token_mint_a, Pubkey::default());
+        assert_ne!(pool_state.token_mint_b, Pubkey::default());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
+    common::{DispatchResult, EventMetadata, EventType},
     protocols::pumpfun::{
         discriminators, pumpfun_create_v2_token_event_log_decode, pumpfun_migrate_event_log_decode,
         pumpfun_trade_event_log_decode, PumpFunCreateTokenEvent, PumpFunCreateV2TokenEvent,
@@ -21,16 +21,24 @@ pub fn parse_pumpfun_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::CREATE_TOKEN_IX => parse_create_token_instruction(data, accounts, metadata),
-        discriminators::CREATE_V2_TOKEN_IX => {
+        discriminators::CREATE_TOKEN_IX => DispatchResult::decode(accounts, 11, || {
+            parse_create_token_instruction(data, accounts, metadata)
+        }),
+        discriminators::CREATE_V2_TOKEN_IX => DispatchResult::decode(accounts, 11, || {
             parse_create_v2_token_instruction(data, accounts, metadata)
+        }),
+        discriminators::BUY_IX | discriminators::BUY_EXACT_SOL_IN_IX => {
+            DispatchResult::decode(accounts, 16, || parse_buy_instruction(data, accounts, metadata))
         }
-        discriminators::BUY_IX | discriminators::BUY_EXACT_SOL_IN_IX => parse_buy_instruction(data, accounts, metadata),
-        discriminators::SELL_IX => parse_sell_instruction(data, accounts, metadata),
-        discriminators::MIGRATE_IX => parse_migrate_instruction(data, accounts, metadata),
-        _ => None,
+        discriminators::SELL_IX => DispatchResult::decode(accounts, 14, || {
+            parse_sell_instruction(data, accounts, metadata)
+        }),
+        discriminators::MIGRATE_IX => DispatchResult::decode(accounts, 24, || {
+            parse_migrate_instruction(data, accounts, metadata)
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -59,19 +67,21 @@ pub fn parse_pumpfun_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::BONDING_CURVE_ACCOUNT => {
             crate::streaming::event_parser::protocols::pumpfun::types::bonding_curve_parser(
                 account, metadata,
             )
+            .into()
         }
         discriminators::GLOBAL_ACCOUNT => {
             crate::streaming::event_parser::protocols::pumpfun::types::global_parser(
                 account, metadata,
             )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -117,7 +127,7 @@ fn parse_create_token_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunCreateToken;
 
-    if data.len() < 16 || accounts.len() < 11 {
+    if data.len() < 16 {
         return None;
     }
     let mut offset = 0;
@@ -180,7 +190,7 @@ fn parse_create_v2_token_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunCreateV2Token;
 
-    if data.len() < 16 || accounts.len() < 11 {
+    if data.len() < 16 {
         return None;
     }
     let mut offset = 0;
@@ -243,7 +253,7 @@ fn parse_buy_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunBuy;
 
-    if data.len() < 16 || accounts.len() < 16 {
+    if data.len() < 16 {
         return None;
     }
     let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
@@ -281,7 +291,7 @@ fn parse_sell_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunSell;
 
-    if data.len() < 16 || accounts.len() < 14 {
+    if data.len() < 16 {
         return None;
     }
     let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
@@ -319,9 +329,6 @@ fn parse_migrate_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunMigrate;
 
-    if accounts.len() < 24 {
-        return None;
-    }
     Some(DexEvent::PumpFunMigrateEvent(PumpFunMigrateEvent {
         metadata,
         global: accounts[0],
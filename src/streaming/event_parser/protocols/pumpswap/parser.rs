@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, DispatchResult, EventMetadata, EventType},
     protocols::pumpswap::{
         discriminators, pump_swap_buy_event_log_decode, pump_swap_create_pool_event_log_decode,
         pump_swap_deposit_event_log_decode, pump_swap_sell_event_log_decode,
@@ -22,16 +22,24 @@ pub fn parse_pumpswap_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::BUY_IX | discriminators::BUY_EXACT_QUOTE_IN_IX => parse_buy_instruction(data, accounts, metadata),
-        discriminators::SELL_IX => parse_sell_instruction(data, accounts, metadata),
-        discriminators::CREATE_POOL_IX => {
-            parse_create_pool_instruction(data, accounts, metadata)
+        discriminators::BUY_IX | discriminators::BUY_EXACT_QUOTE_IN_IX => {
+            DispatchResult::decode(accounts, 13, || parse_buy_instruction(data, accounts, metadata))
         }
-        discriminators::DEPOSIT_IX => parse_deposit_instruction(data, accounts, metadata),
-        discriminators::WITHDRAW_IX => parse_withdraw_instruction(data, accounts, metadata),
-        _ => None,
+        discriminators::SELL_IX => DispatchResult::decode(accounts, 13, || {
+            parse_sell_instruction(data, accounts, metadata)
+        }),
+        discriminators::CREATE_POOL_IX => DispatchResult::decode(accounts, 11, || {
+            parse_create_pool_instruction(data, accounts, metadata)
+        }),
+        discriminators::DEPOSIT_IX => DispatchResult::decode(accounts, 11, || {
+            parse_deposit_instruction(data, accounts, metadata)
+        }),
+        discriminators::WITHDRAW_IX => DispatchResult::decode(accounts, 11, || {
+            parse_withdraw_instruction(data, accounts, metadata)
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -63,15 +71,21 @@ pub fn parse_pumpswap_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::GLOBAL_CONFIG_ACCOUNT => {
-            crate::streaming::event_parser::protocols::pumpswap::types::global_config_parser(account, metadata)
+            crate::streaming::event_parser::protocols::pumpswap::types::global_config_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::POOL_ACCOUNT => {
-            crate::streaming::event_parser::protocols::pumpswap::types::pool_parser(account, metadata)
+            crate::streaming::event_parser::protocols::pumpswap::types::pool_parser(
+                account, metadata,
+            )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -136,7 +150,7 @@ fn parse_buy_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpSwapBuy;
 
-    if data.len() < 16 || accounts.len() < 13 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -173,7 +187,7 @@ fn parse_sell_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpSwapSell;
 
-    if data.len() < 16 || accounts.len() < 13 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -210,7 +224,7 @@ fn parse_create_pool_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpSwapCreatePool;
 
-    if data.len() < 18 || accounts.len() < 11 {
+    if data.len() < 18 {
         return None;
     }
 
@@ -251,7 +265,7 @@ fn parse_deposit_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpSwapDeposit;
 
-    if data.len() < 24 || accounts.len() < 11 {
+    if data.len() < 24 {
         return None;
     }
 
@@ -285,7 +299,7 @@ fn parse_withdraw_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpSwapWithdraw;
 
-    if data.len() < 24 || accounts.len() < 11 {
+    if data.len() < 24 {
         return None;
     }
 
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, DispatchResult, EventMetadata, EventType},
     protocols::raydium_amm_v4::{
         discriminators, RaydiumAmmV4DepositEvent, RaydiumAmmV4Initialize2Event,
         RaydiumAmmV4SwapEvent, RaydiumAmmV4WithdrawEvent, RaydiumAmmV4WithdrawPnlEvent,
@@ -20,21 +20,27 @@ pub fn parse_raydium_amm_v4_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::SWAP_BASE_IN => {
+        discriminators::SWAP_BASE_IN => DispatchResult::decode(accounts, 17, || {
             parse_swap_base_input_instruction(data, accounts, metadata)
-        }
-        discriminators::SWAP_BASE_OUT => {
+        }),
+        discriminators::SWAP_BASE_OUT => DispatchResult::decode(accounts, 17, || {
             parse_swap_base_output_instruction(data, accounts, metadata)
-        }
-        discriminators::DEPOSIT => parse_deposit_instruction(data, accounts, metadata),
-        discriminators::INITIALIZE2 => parse_initialize2_instruction(data, accounts, metadata),
-        discriminators::WITHDRAW => parse_withdraw_instruction(data, accounts, metadata),
-        discriminators::WITHDRAW_PNL => {
+        }),
+        discriminators::DEPOSIT => DispatchResult::decode(accounts, 14, || {
+            parse_deposit_instruction(data, accounts, metadata)
+        }),
+        discriminators::INITIALIZE2 => DispatchResult::decode(accounts, 21, || {
+            parse_initialize2_instruction(data, accounts, metadata)
+        }),
+        discriminators::WITHDRAW => DispatchResult::decode(accounts, 22, || {
+            parse_withdraw_instruction(data, accounts, metadata)
+        }),
+        discriminators::WITHDRAW_PNL => DispatchResult::decode(accounts, 17, || {
             parse_withdraw_pnl_instruction(data, accounts, metadata)
-        }
-        _ => None,
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -57,12 +63,15 @@ pub fn parse_raydium_amm_v4_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::AMM_INFO => {
-            crate::streaming::event_parser::protocols::raydium_amm_v4::types::amm_info_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_amm_v4::types::amm_info_parser(
+                account, metadata,
+            )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -75,10 +84,6 @@ fn parse_withdraw_pnl_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4WithdrawPnl;
 
-    if accounts.len() < 17 {
-        return None;
-    }
-
     Some(DexEvent::RaydiumAmmV4WithdrawPnlEvent(RaydiumAmmV4WithdrawPnlEvent {
         metadata,
         token_program: accounts[0],
@@ -109,7 +114,7 @@ fn parse_withdraw_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4Withdraw;
 
-    if data.len() < 8 || accounts.len() < 22 {
+    if data.len() < 8 {
         return None;
     }
     let amount = read_u64_le(data, 0)?;
@@ -151,7 +156,7 @@ fn parse_initialize2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4Initialize2;
 
-    if data.len() < 25 || accounts.len() < 21 {
+    if data.len() < 25 {
         return None;
     }
     let nonce = data[0];
@@ -198,7 +203,7 @@ fn parse_deposit_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4Deposit;
 
-    if data.len() < 24 || accounts.len() < 14 {
+    if data.len() < 24 {
         return None;
     }
     let max_coin_amount = read_u64_le(data, 0)?;
@@ -236,7 +241,7 @@ fn parse_swap_base_output_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4SwapBaseOut;
 
-    if data.len() < 16 || accounts.len() < 17 {
+    if data.len() < 16 {
         return None;
     }
     let max_amount_in = read_u64_le(data, 0)?;
@@ -285,7 +290,7 @@ fn parse_swap_base_input_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4SwapBaseIn;
 
-    if data.len() < 16 || accounts.len() < 17 {
+    if data.len() < 16 {
         return None;
     }
     let amount_in = read_u64_le(data, 0)?;
@@ -1,7 +1,7 @@
 use crate::streaming::event_parser::{
     common::{
-        read_i32_le, read_option_bool, read_u128_le, read_u64_le, read_u8_le, EventMetadata,
-        EventType,
+        read_i32_le, read_option_bool, read_u128_le, read_u64_le, read_u8_le, DispatchResult,
+        EventMetadata, EventType,
     },
     protocols::raydium_clmm::{
         discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCreatePoolEvent,
@@ -25,27 +25,35 @@ pub fn parse_raydium_clmm_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::SWAP => parse_swap_instruction(data, accounts, metadata),
-        discriminators::SWAP_V2 => parse_swap_v2_instruction(data, accounts, metadata),
-        discriminators::CLOSE_POSITION => {
+        discriminators::SWAP => DispatchResult::decode(accounts, 10, || {
+            parse_swap_instruction(data, accounts, metadata)
+        }),
+        discriminators::SWAP_V2 => DispatchResult::decode(accounts, 13, || {
+            parse_swap_v2_instruction(data, accounts, metadata)
+        }),
+        discriminators::CLOSE_POSITION => DispatchResult::decode(accounts, 6, || {
             parse_close_position_instruction(data, accounts, metadata)
-        }
-        discriminators::DECREASE_LIQUIDITY_V2 => {
+        }),
+        discriminators::DECREASE_LIQUIDITY_V2 => DispatchResult::decode(accounts, 16, || {
             parse_decrease_liquidity_v2_instruction(data, accounts, metadata)
-        }
-        discriminators::CREATE_POOL => parse_create_pool_instruction(data, accounts, metadata),
-        discriminators::INCREASE_LIQUIDITY_V2 => {
+        }),
+        discriminators::CREATE_POOL => DispatchResult::decode(accounts, 13, || {
+            parse_create_pool_instruction(data, accounts, metadata)
+        }),
+        discriminators::INCREASE_LIQUIDITY_V2 => DispatchResult::decode(accounts, 15, || {
             parse_increase_liquidity_v2_instruction(data, accounts, metadata)
-        }
+        }),
         discriminators::OPEN_POSITION_WITH_TOKEN_22_NFT => {
-            parse_open_position_with_token_22_nft_instruction(data, accounts, metadata)
+            DispatchResult::decode(accounts, 20, || {
+                parse_open_position_with_token_22_nft_instruction(data, accounts, metadata)
+            })
         }
-        discriminators::OPEN_POSITION_V2 => {
+        discriminators::OPEN_POSITION_V2 => DispatchResult::decode(accounts, 22, || {
             parse_open_position_v2_instruction(data, accounts, metadata)
-        }
-        _ => None,
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -68,18 +76,27 @@ pub fn parse_raydium_clmm_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::AMM_CONFIG => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::amm_config_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_clmm::types::amm_config_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::POOL_STATE => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::pool_state_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_clmm::types::pool_state_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::TICK_ARRAY_STATE => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::tick_array_state_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_clmm::types::tick_array_state_parser(
+                account, metadata,
+            )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -91,7 +108,7 @@ fn parse_open_position_v2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmOpenPositionV2;
 
-    if data.len() < 51 || accounts.len() < 22 {
+    if data.len() < 51 {
         return None;
     }
     Some(DexEvent::RaydiumClmmOpenPositionV2Event(RaydiumClmmOpenPositionV2Event {
@@ -139,7 +156,7 @@ fn parse_open_position_with_token_22_nft_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmOpenPositionWithToken22Nft;
 
-    if data.len() < 51 || accounts.len() < 20 {
+    if data.len() < 51 {
         return None;
     }
     Some(DexEvent::RaydiumClmmOpenPositionWithToken22NftEvent(
@@ -186,7 +203,7 @@ fn parse_increase_liquidity_v2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmIncreaseLiquidityV2;
 
-    if data.len() < 34 || accounts.len() < 15 {
+    if data.len() < 34 {
         return None;
     }
     Some(DexEvent::RaydiumClmmIncreaseLiquidityV2Event(RaydiumClmmIncreaseLiquidityV2Event {
@@ -221,7 +238,7 @@ fn parse_create_pool_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmCreatePool;
 
-    if data.len() < 24 || accounts.len() < 13 {
+    if data.len() < 24 {
         return None;
     }
     Some(DexEvent::RaydiumClmmCreatePoolEvent(RaydiumClmmCreatePoolEvent {
@@ -252,7 +269,7 @@ fn parse_decrease_liquidity_v2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmDecreaseLiquidityV2;
 
-    if data.len() < 32 || accounts.len() < 16 {
+    if data.len() < 32 {
         return None;
     }
     Some(DexEvent::RaydiumClmmDecreaseLiquidityV2Event(RaydiumClmmDecreaseLiquidityV2Event {
@@ -288,9 +305,6 @@ fn parse_close_position_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmClosePosition;
 
-    if accounts.len() < 6 {
-        return None;
-    }
     Some(DexEvent::RaydiumClmmClosePositionEvent(RaydiumClmmClosePositionEvent {
         metadata,
         nft_owner: accounts[0],
@@ -310,7 +324,7 @@ fn parse_swap_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmSwap;
 
-    if data.len() < 33 || accounts.len() < 10 {
+    if data.len() < 33 {
         return None;
     }
 
@@ -346,7 +360,7 @@ fn parse_swap_v2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumClmmSwapV2;
 
-    if data.len() < 33 || accounts.len() < 13 {
+    if data.len() < 33 {
         return None;
     }
 
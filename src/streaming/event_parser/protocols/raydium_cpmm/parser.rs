@@ -1,7 +1,7 @@
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, DispatchResult, EventMetadata, EventType},
     protocols::raydium_cpmm::{
         discriminators, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent,
         RaydiumCpmmWithdrawEvent,
@@ -21,18 +21,24 @@ pub fn parse_raydium_cpmm_instruction_data(
     data: &[u8],
     accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<DexEvent> {
+) -> DispatchResult {
     match discriminator {
-        discriminators::SWAP_BASE_IN => {
+        discriminators::SWAP_BASE_IN => DispatchResult::decode(accounts, 13, || {
             parse_swap_base_input_instruction(data, accounts, metadata)
-        }
-        discriminators::SWAP_BASE_OUT => {
+        }),
+        discriminators::SWAP_BASE_OUT => DispatchResult::decode(accounts, 13, || {
             parse_swap_base_output_instruction(data, accounts, metadata)
-        }
-        discriminators::DEPOSIT => parse_deposit_instruction(data, accounts, metadata),
-        discriminators::INITIALIZE => parse_initialize_instruction(data, accounts, metadata),
-        discriminators::WITHDRAW => parse_withdraw_instruction(data, accounts, metadata),
-        _ => None,
+        }),
+        discriminators::DEPOSIT => DispatchResult::decode(accounts, 13, || {
+            parse_deposit_instruction(data, accounts, metadata)
+        }),
+        discriminators::INITIALIZE => DispatchResult::decode(accounts, 20, || {
+            parse_initialize_instruction(data, accounts, metadata)
+        }),
+        discriminators::WITHDRAW => DispatchResult::decode(accounts, 14, || {
+            parse_withdraw_instruction(data, accounts, metadata)
+        }),
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -55,15 +61,21 @@ pub fn parse_raydium_cpmm_account_data(
     discriminator: &[u8],
     account: &crate::streaming::grpc::AccountPretty,
     metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+) -> DispatchResult {
     match discriminator {
         discriminators::AMM_CONFIG => {
-            crate::streaming::event_parser::protocols::raydium_cpmm::types::amm_config_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_cpmm::types::amm_config_parser(
+                account, metadata,
+            )
+            .into()
         }
         discriminators::POOL_STATE => {
-            crate::streaming::event_parser::protocols::raydium_cpmm::types::pool_state_parser(account, metadata)
+            crate::streaming::event_parser::protocols::raydium_cpmm::types::pool_state_parser(
+                account, metadata,
+            )
+            .into()
         }
-        _ => None,
+        _ => DispatchResult::Unknown,
     }
 }
 
@@ -76,7 +88,7 @@ fn parse_withdraw_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumCpmmWithdraw;
 
-    if data.len() < 24 || accounts.len() < 14 {
+    if data.len() < 24 {
         return None;
     }
     Some(DexEvent::RaydiumCpmmWithdrawEvent(RaydiumCpmmWithdrawEvent {
@@ -109,7 +121,7 @@ fn parse_initialize_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumCpmmInitialize;
 
-    if data.len() < 24 || accounts.len() < 20 {
+    if data.len() < 24 {
         return None;
     }
     Some(DexEvent::RaydiumCpmmInitializeEvent(RaydiumCpmmInitializeEvent {
@@ -148,7 +160,7 @@ fn parse_deposit_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumCpmmDeposit;
 
-    if data.len() < 24 || accounts.len() < 13 {
+    if data.len() < 24 {
         return None;
     }
     Some(DexEvent::RaydiumCpmmDepositEvent(RaydiumCpmmDepositEvent {
@@ -180,7 +192,7 @@ fn parse_swap_base_input_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumCpmmSwapBaseInput;
 
-    if data.len() < 16 || accounts.len() < 13 {
+    if data.len() < 16 {
         return None;
     }
 
@@ -215,7 +227,7 @@ fn parse_swap_base_output_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumCpmmSwapBaseOutput;
 
-    if data.len() < 16 || accounts.len() < 13 {
+    if data.len() < 16 {
         return None;
     }
 
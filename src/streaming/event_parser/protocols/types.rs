@@ -1,6 +1,7 @@
 use crate::streaming::event_parser::protocols::{
     bonk::parser::BONK_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
-    pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
+    meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID,
+    orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID, pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
     raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
     raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
 };
@@ -17,6 +18,8 @@ pub enum Protocol {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    MeteoraDlmm,
+    OrcaWhirlpool,
 }
 
 impl Protocol {
@@ -29,6 +32,8 @@ impl Protocol {
             Protocol::RaydiumClmm => vec![RAYDIUM_CLMM_PROGRAM_ID],
             Protocol::RaydiumAmmV4 => vec![RAYDIUM_AMM_V4_PROGRAM_ID],
             Protocol::MeteoraDammV2 => vec![METEORA_DAMM_V2_PROGRAM_ID],
+            Protocol::MeteoraDlmm => vec![METEORA_DLMM_PROGRAM_ID],
+            Protocol::OrcaWhirlpool => vec![ORCA_WHIRLPOOL_PROGRAM_ID],
         }
     }
 }
@@ -43,6 +48,8 @@ impl std::fmt::Display for Protocol {
             Protocol::RaydiumClmm => write!(f, "RaydiumClmm"),
             Protocol::RaydiumAmmV4 => write!(f, "RaydiumAmmV4"),
             Protocol::MeteoraDammV2 => write!(f, "MeteoraDammV2"),
+            Protocol::MeteoraDlmm => write!(f, "MeteoraDlmm"),
+            Protocol::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
         }
     }
 }
@@ -59,6 +66,8 @@ impl std::str::FromStr for Protocol {
             "raydiumclmm" => Ok(Protocol::RaydiumClmm),
             "raydiumammv4" => Ok(Protocol::RaydiumAmmV4),
             "meteoradamm_v2" => Ok(Protocol::MeteoraDammV2),
+            "meteoradlmm" => Ok(Protocol::MeteoraDlmm),
+            "orcawhirlpool" => Ok(Protocol::OrcaWhirlpool),
             _ => Err(anyhow!("Unsupported protocol: {}", s)),
         }
     }
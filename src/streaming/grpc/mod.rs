@@ -13,4 +13,5 @@ pub use types::*;
 // 从公用模块重新导出
 pub use crate::streaming::common::{
     ConnectionConfig, MetricsManager, PerformanceMetrics, StreamClientConfig as ClientConfig,
+    StreamClientConfigBuilder as ClientConfigBuilder,
 };
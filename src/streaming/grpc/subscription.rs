@@ -41,18 +41,14 @@ impl SubscriptionManager {
         Ok(builder.connect().await?)
     }
 
-    /// Create subscription request and return stream
-    pub async fn subscribe_with_request(
+    /// Build the subscription request without connecting
+    pub fn build_subscribe_request(
         &self,
         transactions: Option<TransactionsFilterMap>,
         accounts: Option<AccountsFilterMap>,
         commitment: Option<CommitmentLevel>,
         event_type_filter: Option<&EventTypeFilter>,
-    ) -> AnyResult<(
-        impl Sink<SubscribeRequest, Error = mpsc::SendError>,
-        impl Stream<Item = Result<SubscribeUpdate, Status>>,
-        SubscribeRequest,
-    )> {
+    ) -> SubscribeRequest {
         let blocks_meta =
             if event_type_filter.is_some() && event_type_filter.unwrap().include_block_event() {
                 hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
@@ -61,7 +57,7 @@ impl SubscriptionManager {
             } else {
                 hashmap! {}
             };
-        let subscribe_request = SubscribeRequest {
+        SubscribeRequest {
             accounts: accounts.unwrap_or_default(),
             transactions: transactions.unwrap_or_default(),
             blocks_meta,
@@ -71,12 +67,40 @@ impl SubscriptionManager {
                 Some(CommitmentLevel::Processed.into())
             },
             ..Default::default()
-        };
+        }
+    }
+
+    /// Open a subscription for a prepared request and return stream
+    pub async fn subscribe(
+        &self,
+        subscribe_request: SubscribeRequest,
+    ) -> AnyResult<(
+        impl Sink<SubscribeRequest, Error = mpsc::SendError>,
+        impl Stream<Item = Result<SubscribeUpdate, Status>>,
+        SubscribeRequest,
+    )> {
         let mut client = self.connect().await?;
         let (sink, stream) = client.subscribe_with_request(Some(subscribe_request.clone())).await?;
         Ok((sink, stream, subscribe_request))
     }
 
+    /// Create subscription request and return stream
+    pub async fn subscribe_with_request(
+        &self,
+        transactions: Option<TransactionsFilterMap>,
+        accounts: Option<AccountsFilterMap>,
+        commitment: Option<CommitmentLevel>,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> AnyResult<(
+        impl Sink<SubscribeRequest, Error = mpsc::SendError>,
+        impl Stream<Item = Result<SubscribeUpdate, Status>>,
+        SubscribeRequest,
+    )> {
+        let subscribe_request =
+            self.build_subscribe_request(transactions, accounts, commitment, event_type_filter);
+        self.subscribe(subscribe_request).await
+    }
+
     /// Create account subscription request and return stream
     pub fn subscribe_with_account_request(
         &self,
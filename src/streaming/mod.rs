@@ -1,6 +1,7 @@
 pub mod common;
 pub mod event_parser;
 pub mod grpc;
+pub mod recorder;
 pub mod shred;
 pub mod shred_stream;
 pub mod yellowstone_grpc;
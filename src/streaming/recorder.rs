@@ -0,0 +1,299 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use prost::Message;
+use solana_sdk::pubkey::Pubkey;
+use tokio::time::Instant;
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+
+use crate::common::AnyResult;
+use crate::streaming::common::process_grpc_transaction;
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::{DexEvent, Protocol};
+use crate::streaming::grpc::pool::factory;
+use crate::streaming::grpc::EventPretty;
+
+/// 录制文件中的一条记录
+#[derive(Debug, Clone)]
+pub struct RecordedUpdate {
+    /// 录制时的接收时间（微秒）
+    pub recv_us: i64,
+    /// 原始 gRPC 更新
+    pub update: SubscribeUpdate,
+}
+
+/// 回放速度
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReplaySpeed {
+    /// 不等待，尽快回放
+    #[default]
+    Instant,
+    /// 按录制时的时间间隔回放
+    Original,
+    /// 按录制时间间隔除以倍数回放
+    Accelerated(f64),
+}
+
+impl ReplaySpeed {
+    fn delay(&self, elapsed_us: i64) -> Option<Duration> {
+        let elapsed = Duration::from_micros(elapsed_us.max(0) as u64);
+        match self {
+            ReplaySpeed::Instant => None,
+            ReplaySpeed::Original => Some(elapsed),
+            ReplaySpeed::Accelerated(factor) if *factor > 0.0 => Some(elapsed.div_f64(*factor)),
+            ReplaySpeed::Accelerated(_) => None,
+        }
+    }
+}
+
+/// 把原始 `SubscribeUpdate` 录制到文件
+///
+/// 每条记录为 `recv_us`（8 字节小端）+ 消息长度（4 字节小端）+ protobuf 编码的消息。
+pub struct EventRecorder {
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl EventRecorder {
+    /// 创建录制文件，已存在时覆盖
+    pub fn create(path: impl AsRef<Path>) -> AnyResult<Self> {
+        Ok(Self { writer: Mutex::new(BufWriter::new(File::create(path)?)) })
+    }
+
+    /// 以当前时间写入一条更新
+    pub fn record(&self, update: &SubscribeUpdate) -> AnyResult<()> {
+        self.record_at(get_high_perf_clock(), update)
+    }
+
+    /// 以指定接收时间写入一条更新
+    pub fn record_at(&self, recv_us: i64, update: &SubscribeUpdate) -> AnyResult<()> {
+        let data = update.encode_to_vec();
+        let mut writer = self.writer.lock();
+        writer.write_all(&recv_us.to_le_bytes())?;
+        writer.write_all(&(data.len() as u32).to_le_bytes())?;
+        writer.write_all(&data)?;
+        Ok(())
+    }
+
+    /// 把缓冲数据写入文件
+    pub fn flush(&self) -> AnyResult<()> {
+        self.writer.lock().flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for EventRecorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.writer.get_mut().flush() {
+            log::error!("Failed to flush event recorder: {e}");
+        }
+    }
+}
+
+/// 读取 `EventRecorder` 录制的文件并回放
+pub struct EventReplayer {
+    reader: BufReader<File>,
+}
+
+impl EventReplayer {
+    /// 打开录制文件
+    pub fn open(path: impl AsRef<Path>) -> AnyResult<Self> {
+        Ok(Self { reader: BufReader::new(File::open(path)?) })
+    }
+
+    /// 读取下一条记录，文件结束时返回 `None`
+    ///
+    /// 录制中断留下的不完整末尾记录按文件结束处理，并输出警告。
+    pub fn next_update(&mut self) -> AnyResult<Option<RecordedUpdate>> {
+        let mut recv_us = [0u8; 8];
+        match read_full(&mut self.reader, &mut recv_us)? {
+            0 => return Ok(None),
+            8 => {}
+            n => return Ok(truncated_record(n)),
+        }
+        let mut len = [0u8; 4];
+        let n = read_full(&mut self.reader, &mut len)?;
+        if n < len.len() {
+            return Ok(truncated_record(recv_us.len() + n));
+        }
+        let mut data = vec![0u8; u32::from_le_bytes(len) as usize];
+        let n = read_full(&mut self.reader, &mut data)?;
+        if n < data.len() {
+            return Ok(truncated_record(recv_us.len() + len.len() + n));
+        }
+        Ok(Some(RecordedUpdate {
+            recv_us: i64::from_le_bytes(recv_us),
+            update: SubscribeUpdate::decode(data.as_slice())?,
+        }))
+    }
+
+    /// Replay every recorded update through the event parser, invoking `callback` for each
+    /// parsed event in recorded order. Returns the number of updates replayed.
+    pub async fn replay(
+        mut self,
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        bot_wallet: Option<Pubkey>,
+        speed: ReplaySpeed,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> AnyResult<usize> {
+        let started = Instant::now();
+        let mut first_recv_us = None;
+        let mut replayed = 0;
+        while let Some(recorded) = self.next_update()? {
+            let first_recv_us = *first_recv_us.get_or_insert(recorded.recv_us);
+            if let Some(delay) = speed.delay(recorded.recv_us - first_recv_us) {
+                tokio::time::sleep_until(started + delay).await;
+            }
+
+            let created_at = recorded.update.created_at;
+            let event_pretty = match recorded.update.update_oneof {
+                Some(UpdateOneof::Account(account)) => {
+                    EventPretty::Account(factory::create_account_pretty_pooled(account))
+                }
+                Some(UpdateOneof::BlockMeta(block_meta)) => EventPretty::BlockMeta(
+                    factory::create_block_meta_pretty_pooled(block_meta, created_at),
+                ),
+                Some(UpdateOneof::Transaction(transaction)) => EventPretty::Transaction(
+                    factory::create_transaction_pretty_pooled(transaction, created_at),
+                ),
+                _ => continue,
+            };
+            if let Err(e) = process_grpc_transaction(
+                event_pretty,
+                protocols,
+                event_type_filter,
+                callback.clone(),
+                bot_wallet,
+                None,
+            )
+            .await
+            {
+                log::error!("Error replaying recorded update: {e:?}");
+            }
+            replayed += 1;
+        }
+        Ok(replayed)
+    }
+}
+
+/// 尽量读满 `buf`，返回读取的字节数，只有文件结束时才少于 `buf.len()`
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn truncated_record(read: usize) -> Option<RecordedUpdate> {
+    log::warn!("Ignoring incomplete record at the end of the recording ({read} bytes)");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::geyser::SubscribeUpdateBlockMeta;
+
+    fn block_meta_update(slot: u64) -> SubscribeUpdate {
+        SubscribeUpdate {
+            filters: vec!["block_meta".to_string()],
+            update_oneof: Some(UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+                slot,
+                blockhash: format!("hash-{slot}"),
+                parent_slot: slot - 1,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_updates_in_order() {
+        let path = std::env::temp_dir()
+            .join(format!("solana-streamer-recorder-{}.bin", std::process::id()));
+        let updates: Vec<_> = (10..13).map(block_meta_update).collect();
+        {
+            let recorder = EventRecorder::create(&path).unwrap();
+            for (index, update) in updates.iter().enumerate() {
+                recorder.record_at(1_000 * (index as i64 + 1), update).unwrap();
+            }
+        }
+
+        let mut replayer = EventReplayer::open(&path).unwrap();
+        for (index, update) in updates.iter().enumerate() {
+            let recorded = replayer.next_update().unwrap().expect("missing recorded update");
+            assert_eq!(recorded.recv_us, 1_000 * (index as i64 + 1));
+            assert_eq!(&recorded.update, update);
+        }
+        assert!(replayer.next_update().unwrap().is_none());
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let replayed = EventReplayer::open(&path)
+            .unwrap()
+            .replay(
+                &[],
+                None,
+                None,
+                ReplaySpeed::Instant,
+                Arc::new(move |event| {
+                    if let DexEvent::BlockMetaEvent(block_meta) = event {
+                        sink.lock().push((block_meta.slot, block_meta.block_hash));
+                    }
+                }),
+            )
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(replayed, 3);
+        assert_eq!(
+            *events.lock(),
+            vec![
+                (10, "hash-10".to_string()),
+                (11, "hash-11".to_string()),
+                (12, "hash-12".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated_final_record_ends_the_replay() {
+        let path = std::env::temp_dir()
+            .join(format!("solana-streamer-recorder-truncated-{}.bin", std::process::id()));
+        let updates: Vec<_> = (10..13).map(block_meta_update).collect();
+        {
+            let recorder = EventRecorder::create(&path).unwrap();
+            for update in &updates {
+                recorder.record_at(0, update).unwrap();
+            }
+        }
+        let full_len = std::fs::metadata(&path).unwrap().len();
+        let last_record_len = 12 + updates[2].encoded_len() as u64;
+
+        // 截断在消息体、长度前缀和接收时间中间
+        for cut in [1, last_record_len - 10, last_record_len - 3] {
+            let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+            file.set_len(full_len - cut).unwrap();
+
+            let mut replayer = EventReplayer::open(&path).unwrap();
+            for update in &updates[..2] {
+                assert_eq!(&replayer.next_update().unwrap().unwrap().update, update);
+            }
+            assert!(replayer.next_update().unwrap().is_none(), "cut {cut} bytes");
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
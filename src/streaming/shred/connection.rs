@@ -7,6 +7,8 @@ use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient
 use crate::streaming::common::{
     MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
 };
+use crate::streaming::event_parser::common::{ParseError, ParseErrorCallback};
+use crate::streaming::shred::{ShredDedupConfig, ShredDedupStats};
 
 /// ShredStream gRPC 客户端
 #[derive(Clone)]
@@ -14,6 +16,11 @@ pub struct ShredStreamGrpc {
     pub shredstream_client: Arc<ShredstreamProxyClient<Channel>>,
     pub config: StreamClientConfig,
     pub subscription_handle: Arc<Mutex<Option<SubscriptionHandle>>>,
+    /// 交易去重配置，None 时不去重
+    pub dedup: Option<ShredDedupConfig>,
+    pub dedup_stats: Arc<ShredDedupStats>,
+    /// 解析失败回调
+    pub parse_error_callback: Option<ParseErrorCallback>,
 }
 
 impl ShredStreamGrpc {
@@ -30,9 +37,32 @@ impl ShredStreamGrpc {
             shredstream_client: Arc::new(shredstream_client),
             config,
             subscription_handle: Arc::new(Mutex::new(None)),
+            dedup: None,
+            dedup_stats: Arc::new(ShredDedupStats::default()),
+            parse_error_callback: None,
         })
     }
 
+    /// 启用交易去重，丢弃重复和早于去重窗口的交易
+    pub fn with_dedup(mut self, config: ShredDedupConfig) -> Self {
+        self.dedup = Some(config);
+        self
+    }
+
+    /// 设置解析失败回调，已知指令解码失败时调用
+    pub fn on_parse_error<F>(mut self, on_parse_error: F) -> Self
+    where
+        F: Fn(ParseError) + Send + Sync + 'static,
+    {
+        self.parse_error_callback = Some(Arc::new(on_parse_error));
+        self
+    }
+
+    /// 获取去重计数
+    pub fn dedup_stats(&self) -> &ShredDedupStats {
+        &self.dedup_stats
+    }
+
     /// 获取当前配置
     pub fn get_config(&self) -> &StreamClientConfig {
         &self.config
@@ -0,0 +1,225 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use solana_sdk::signature::Signature;
+
+/// 默认保留去重状态的 slot 数
+pub const DEFAULT_DEDUP_MAX_BUFFERED_SLOTS: u64 = 32;
+
+/// ShredStream 去重配置
+#[derive(Debug, Clone)]
+pub struct ShredDedupConfig {
+    /// 保留去重状态的 slot 数，早于窗口的交易视为迟到并丢弃（默认：32）
+    pub max_buffered_slots: u64,
+    /// 按 slot 排序时最多等待的 slot 数，0 表示不排序、收到即处理（默认：0）
+    ///
+    /// slot N 的交易会缓存到收到 slot N + `reorder_slots` 的数据后，再按 slot 升序输出，
+    /// 因此每多等待一个 slot 约增加 400ms 延迟。已输出的 slot 再收到数据时立即输出。
+    pub reorder_slots: u64,
+}
+
+impl Default for ShredDedupConfig {
+    fn default() -> Self {
+        Self { max_buffered_slots: DEFAULT_DEDUP_MAX_BUFFERED_SLOTS, reorder_slots: 0 }
+    }
+}
+
+/// 去重计数
+#[derive(Debug, Default)]
+pub struct ShredDedupStats {
+    duplicates_dropped: AtomicU64,
+    late_dropped: AtomicU64,
+}
+
+impl ShredDedupStats {
+    /// 因重复被丢弃的交易数
+    pub fn duplicates_dropped(&self) -> u64 {
+        self.duplicates_dropped.load(Ordering::Relaxed)
+    }
+
+    /// 因 slot 早于去重窗口被丢弃的交易数
+    pub fn late_dropped(&self) -> u64 {
+        self.late_dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// 按 (slot, signature) 去重 ShredStream 交易
+///
+/// ShredStream proxy 推送的是已重组的 entry，这里处理多路来源带来的重复和迟到数据，
+/// 排序由 [`SlotReorderBuffer`] 负责。
+/// 状态只保留最近 `max_buffered_slots` 个 slot，以限制内存占用。
+pub struct EntryDeduplicator {
+    max_buffered_slots: u64,
+    highest_slot: u64,
+    seen: BTreeMap<u64, HashSet<Signature>>,
+    stats: Arc<ShredDedupStats>,
+}
+
+impl EntryDeduplicator {
+    pub fn new(config: &ShredDedupConfig, stats: Arc<ShredDedupStats>) -> Self {
+        Self {
+            max_buffered_slots: config.max_buffered_slots.max(1),
+            highest_slot: 0,
+            seen: BTreeMap::new(),
+            stats,
+        }
+    }
+
+    /// 返回该交易是否应当处理，重复或迟到时返回 false
+    pub fn accept(&mut self, slot: u64, signature: Signature) -> bool {
+        if slot + self.max_buffered_slots <= self.highest_slot {
+            self.stats.late_dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        if !self.seen.entry(slot).or_default().insert(signature) {
+            self.stats.duplicates_dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        if slot > self.highest_slot {
+            self.highest_slot = slot;
+            let oldest = slot.saturating_sub(self.max_buffered_slots - 1);
+            self.seen = self.seen.split_off(&oldest);
+        }
+        true
+    }
+}
+
+/// 按 slot 排序 ShredStream 交易
+///
+/// 同一 slot 内保持到达顺序；最多缓存 `reorder_slots + 1` 个 slot。
+pub struct SlotReorderBuffer<T> {
+    reorder_slots: u64,
+    highest_slot: u64,
+    released_through: Option<u64>,
+    pending: BTreeMap<u64, Vec<T>>,
+}
+
+impl<T> SlotReorderBuffer<T> {
+    pub fn new(reorder_slots: u64) -> Self {
+        Self { reorder_slots, highest_slot: 0, released_through: None, pending: BTreeMap::new() }
+    }
+
+    /// 缓存一笔交易，返回按 slot 升序可以输出的交易
+    pub fn push(&mut self, slot: u64, item: T) -> Vec<(u64, T)> {
+        // 该 slot 已经输出过，无法再保证顺序，直接输出
+        if self.released_through.is_some_and(|released| slot <= released) {
+            return vec![(slot, item)];
+        }
+        self.pending.entry(slot).or_default().push(item);
+        self.highest_slot = self.highest_slot.max(slot);
+
+        let mut ready = Vec::new();
+        while let Some(entry) = self.pending.first_entry() {
+            let slot = *entry.key();
+            if slot + self.reorder_slots > self.highest_slot {
+                break;
+            }
+            ready.extend(entry.remove().into_iter().map(|item| (slot, item)));
+            self.released_through = Some(slot);
+        }
+        ready
+    }
+
+    /// 输出全部缓存的交易，用于订阅流结束时
+    pub fn drain(&mut self) -> Vec<(u64, T)> {
+        let pending = std::mem::take(&mut self.pending);
+        if let Some(&slot) = pending.keys().next_back() {
+            self.released_through = Some(slot);
+        }
+        pending
+            .into_iter()
+            .flat_map(|(slot, items)| items.into_iter().map(move |item| (slot, item)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deduplicator(max_buffered_slots: u64) -> (EntryDeduplicator, Arc<ShredDedupStats>) {
+        let stats = Arc::new(ShredDedupStats::default());
+        let config = ShredDedupConfig { max_buffered_slots, ..Default::default() };
+        (EntryDeduplicator::new(&config, stats.clone()), stats)
+    }
+
+    #[test]
+    fn drops_duplicate_signatures_in_the_same_slot() {
+        let (mut dedup, stats) = deduplicator(4);
+        let signature = Signature::new_unique();
+
+        assert!(dedup.accept(100, signature));
+        assert!(!dedup.accept(100, signature));
+        assert!(dedup.accept(101, signature));
+        assert!(dedup.accept(100, Signature::new_unique()));
+        assert_eq!(stats.duplicates_dropped(), 1);
+        assert_eq!(stats.late_dropped(), 0);
+    }
+
+    #[test]
+    fn drops_slots_older_than_the_window() {
+        let (mut dedup, stats) = deduplicator(4);
+
+        assert!(dedup.accept(104, Signature::new_unique()));
+        assert!(dedup.accept(101, Signature::new_unique()));
+        assert!(!dedup.accept(100, Signature::new_unique()));
+        assert_eq!(stats.late_dropped(), 1);
+        assert_eq!(stats.duplicates_dropped(), 0);
+    }
+
+    #[test]
+    fn evicts_slots_that_leave_the_window() {
+        let (mut dedup, stats) = deduplicator(4);
+        let signature = Signature::new_unique();
+
+        assert!(dedup.accept(100, signature));
+        assert!(dedup.accept(101, Signature::new_unique()));
+        assert!(dedup.accept(103, Signature::new_unique()));
+        assert_eq!(dedup.seen.keys().copied().collect::<Vec<_>>(), vec![100, 101, 103]);
+
+        assert!(dedup.accept(105, Signature::new_unique()));
+        assert_eq!(dedup.seen.keys().copied().collect::<Vec<_>>(), vec![103, 105]);
+
+        // 被淘汰的 slot 再次出现时按迟到处理，而不是重复
+        assert!(!dedup.accept(100, signature));
+        assert_eq!(stats.late_dropped(), 1);
+        assert_eq!(stats.duplicates_dropped(), 0);
+    }
+
+    #[test]
+    fn emits_duplicated_out_of_order_transactions_once_in_slot_order() {
+        let (mut dedup, stats) = deduplicator(8);
+        let mut reorder = SlotReorderBuffer::new(2);
+        let a = Signature::new_unique();
+        let b = Signature::new_unique();
+        let c = Signature::new_unique();
+        let d = Signature::new_unique();
+        let e = Signature::new_unique();
+        let arrivals = [(101, c), (100, a), (101, c), (100, b), (100, a), (102, d), (103, e)];
+
+        let mut output = Vec::new();
+        for (slot, signature) in arrivals {
+            if dedup.accept(slot, signature) {
+                output.extend(reorder.push(slot, signature));
+            }
+        }
+        // 102 和 103 仍在等待后续 slot
+        assert_eq!(output, vec![(100, a), (100, b), (101, c)]);
+        output.extend(reorder.drain());
+
+        assert_eq!(output, vec![(100, a), (100, b), (101, c), (102, d), (103, e)]);
+        assert_eq!(stats.duplicates_dropped(), 2);
+        assert_eq!(stats.late_dropped(), 0);
+    }
+
+    #[test]
+    fn passes_through_without_reordering_and_after_release() {
+        let mut reorder = SlotReorderBuffer::new(0);
+        assert_eq!(reorder.push(101, 'a'), vec![(101, 'a')]);
+        // 已输出 slot 之前的数据无法再排序，立即输出
+        assert_eq!(reorder.push(100, 'b'), vec![(100, 'b')]);
+        assert_eq!(reorder.push(102, 'c'), vec![(102, 'c')]);
+        assert!(reorder.drain().is_empty());
+    }
+}
@@ -2,15 +2,18 @@ use std::sync::Arc;
 
 use futures::StreamExt;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
 
 use crate::common::AnyResult;
 use crate::protos::shredstream::SubscribeEntriesRequest;
 use crate::streaming::common::{process_shred_transaction, SubscriptionHandle};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::ParseErrorCallback;
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::MetricsManager;
 use crate::streaming::shred::pool::factory;
+use crate::streaming::shred::{EntryDeduplicator, SlotReorderBuffer};
 use log::error;
 use solana_entry::entry::Entry;
 
@@ -43,36 +46,54 @@ impl ShredStreamGrpc {
         let mut stream = client.subscribe_entries(request).await?.into_inner();
 
         // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(callback);
+        let mut deduplicator = self
+            .dedup
+            .as_ref()
+            .map(|config| EntryDeduplicator::new(config, self.dedup_stats.clone()));
+        let mut reorder = self
+            .dedup
+            .as_ref()
+            .filter(|config| config.reorder_slots > 0)
+            .map(|config| SlotReorderBuffer::new(config.reorder_slots));
+        let parse_error_callback = self.parse_error_callback.clone();
 
         let stream_task = tokio::spawn(async move {
             while let Some(message) = stream.next().await {
                 match message {
                     Ok(msg) => {
+                        let mut ready = Vec::new();
                         if let Ok(entries) = bincode::deserialize::<Vec<Entry>>(&msg.entries) {
                             for entry in entries {
                                 for transaction in entry.transactions {
-                                    let transaction_with_slot =
-                                        factory::create_transaction_with_slot_pooled(
-                                            transaction.clone(),
-                                            msg.slot,
-                                            get_high_perf_clock(),
-                                        );
-                                    // Process transaction - clone Arc and Vec for each call
-                                    if let Err(e) = process_shred_transaction(
-                                        transaction_with_slot,
-                                        &protocols,
-                                        event_type_filter.as_ref(),
-                                        callback.clone(),
-                                        bot_wallet,
-                                    )
-                                    .await
+                                    if let (Some(deduplicator), Some(signature)) =
+                                        (deduplicator.as_mut(), transaction.signatures.first())
                                     {
-                                        error!("Error handling message: {e:?}");
+                                        if !deduplicator.accept(msg.slot, *signature) {
+                                            continue;
+                                        }
+                                    }
+                                    match reorder.as_mut() {
+                                        Some(reorder) => {
+                                            ready.extend(reorder.push(msg.slot, transaction))
+                                        }
+                                        None => ready.push((msg.slot, transaction)),
                                     }
                                 }
                             }
                         }
+                        for (slot, transaction) in ready {
+                            process_entry_transaction(
+                                slot,
+                                transaction,
+                                &protocols,
+                                event_type_filter.as_ref(),
+                                callback.clone(),
+                                bot_wallet,
+                                parse_error_callback.as_ref(),
+                            )
+                            .await;
+                        }
                         continue;
                     }
                     Err(error) => {
@@ -81,6 +102,21 @@ impl ShredStreamGrpc {
                     }
                 }
             }
+            // 流结束时输出仍在等待排序的交易
+            if let Some(reorder) = reorder.as_mut() {
+                for (slot, transaction) in reorder.drain() {
+                    process_entry_transaction(
+                        slot,
+                        transaction,
+                        &protocols,
+                        event_type_filter.as_ref(),
+                        callback.clone(),
+                        bot_wallet,
+                        parse_error_callback.as_ref(),
+                    )
+                    .await;
+                }
+            }
         });
 
         // 保存订阅句柄
@@ -91,3 +127,29 @@ impl ShredStreamGrpc {
         Ok(())
     }
 }
+
+/// 解析一笔 ShredStream 交易并回调其中的事件
+async fn process_entry_transaction(
+    slot: u64,
+    transaction: VersionedTransaction,
+    protocols: &[Protocol],
+    event_type_filter: Option<&EventTypeFilter>,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    bot_wallet: Option<Pubkey>,
+    parse_error_callback: Option<&ParseErrorCallback>,
+) {
+    let transaction_with_slot =
+        factory::create_transaction_with_slot_pooled(transaction, slot, get_high_perf_clock());
+    if let Err(e) = process_shred_transaction(
+        transaction_with_slot,
+        protocols,
+        event_type_filter,
+        callback,
+        bot_wallet,
+        parse_error_callback,
+    )
+    .await
+    {
+        error!("Error handling message: {e:?}");
+    }
+}
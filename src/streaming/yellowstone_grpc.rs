@@ -1,24 +1,42 @@
 use crate::common::AnyResult;
 use crate::streaming::common::{
-    process_grpc_transaction, MetricsManager, PerformanceMetrics, StreamClientConfig,
-    SubscriptionHandle,
+    backfill_transactions, process_grpc_transaction, CallbackPool, CallbackPoolMetrics,
+    CallbackWorkers,
+    MetricsManager, PerformanceMetrics, ReconnectConfig, SlotGap, SlotGapDetector,
+    StreamClientConfig, StreamMetrics, StreamMetricsCollector, SubscriptionHandle,
+    DEFAULT_LIVENESS_TIMEOUT_MS, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_MS,
 };
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::{ParseError, ParseErrorCallback, ProtocolType};
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::pool::factory;
-use crate::streaming::grpc::{EventPretty, SubscriptionManager};
+use crate::streaming::grpc::{
+    AccountsFilterMap, EventPretty, SubscriptionManager, TransactionsFilterMap,
+};
+use crate::streaming::recorder::EventRecorder;
 use anyhow::anyhow;
 use chrono::Local;
 use futures::channel::mpsc;
-use futures::{SinkExt, StreamExt};
+use futures::{Sink, SinkExt, Stream, StreamExt};
 use log::error;
+use prost::Message;
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tonic::Status;
+use yellowstone_grpc_proto::geyser::subscribe_request_filter_accounts_filter::Filter;
+use yellowstone_grpc_proto::geyser::subscribe_request_filter_accounts_filter_memcmp::Data;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
 use yellowstone_grpc_proto::geyser::{
-    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter, SubscribeRequestPing,
+    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestPing, SubscribeUpdate,
 };
 
 /// 交易过滤器
@@ -37,6 +55,98 @@ pub struct AccountFilter {
     pub filters: Vec<SubscribeRequestFilterAccountsFilter>,
 }
 
+impl AccountFilter {
+    /// memcmp 过滤：账户数据从 `offset` 开始与 `bytes` 相同
+    pub fn memcmp(offset: u64, bytes: impl Into<Vec<u8>>) -> SubscribeRequestFilterAccountsFilter {
+        SubscribeRequestFilterAccountsFilter {
+            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                offset,
+                data: Some(Data::Bytes(bytes.into())),
+            })),
+        }
+    }
+
+    /// dataSize 过滤：账户数据长度等于 `size`
+    pub fn data_size(size: u64) -> SubscribeRequestFilterAccountsFilter {
+        SubscribeRequestFilterAccountsFilter { filter: Some(Filter::Datasize(size)) }
+    }
+}
+
+/// 交易与账户订阅的 commitment 覆盖，未设置时回退到统一的 commitment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommitmentOverrides {
+    /// 交易与 block meta 的 commitment
+    pub transactions: Option<CommitmentLevel>,
+    /// 账户的 commitment
+    pub accounts: Option<CommitmentLevel>,
+}
+
+impl CommitmentOverrides {
+    /// 返回 (交易 commitment, 账户 commitment)
+    pub fn resolve(
+        &self,
+        fallback: Option<CommitmentLevel>,
+    ) -> (Option<CommitmentLevel>, Option<CommitmentLevel>) {
+        (self.transactions.or(fallback), self.accounts.or(fallback))
+    }
+}
+
+/// 卡顿回调，参数为未收到任何消息的时长
+pub type StallCallback = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// 存活检测配置
+#[derive(Clone)]
+pub struct LivenessConfig {
+    /// 超过该时长未收到任何消息（包括 ping）即视为卡顿
+    pub timeout: Duration,
+    /// 卡顿时调用的回调
+    pub on_stall: Option<StallCallback>,
+    /// 卡顿时是否强制重连，仅对 `subscribe_events_resilient` 生效
+    pub reconnect: bool,
+}
+
+impl Default for LivenessConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_millis(DEFAULT_LIVENESS_TIMEOUT_MS),
+            on_stall: None,
+            reconnect: false,
+        }
+    }
+}
+
+/// 连接状态，由 `subscribe_events_resilient` 通过状态回调通知
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    /// 订阅已建立
+    Connected,
+    /// 订阅流出错或结束
+    Disconnected { last_slot: u64 },
+    /// 正在进行第 `attempt` 次重连
+    Reconnecting { attempt: u32, last_slot: u64 },
+}
+
+/// `YellowstoneGrpc::stop` 返回的最终统计
+#[derive(Debug, Clone, Default)]
+pub struct StreamSummary {
+    /// 自本次订阅开始以来的运行时长，没有订阅时为 0
+    pub runtime: Duration,
+    /// 交给回调的事件总数
+    pub events_processed: u64,
+    /// 按协议统计的事件数
+    pub events_by_protocol: HashMap<ProtocolType, u64>,
+    /// 最近收到的 slot
+    pub last_slot: u64,
+    /// 成功重连次数
+    pub reconnects: u64,
+}
+
+/// 连接状态回调
+pub type StatusCallback = Arc<dyn Fn(ConnectionStatus) + Send + Sync>;
+
+/// 账户订阅流的控制通道及其 commitment
+pub type AccountControl = (mpsc::Sender<SubscribeRequest>, Option<i32>);
+
 pub struct YellowstoneGrpc {
     pub endpoint: String,
     pub x_token: Option<String>,
@@ -47,6 +157,26 @@ pub struct YellowstoneGrpc {
     pub active_subscription: Arc<AtomicBool>,
     pub control_tx: Arc<tokio::sync::Mutex<Option<mpsc::Sender<SubscribeRequest>>>>,
     pub current_request: Arc<tokio::sync::RwLock<Option<SubscribeRequest>>>,
+    /// 账户使用独立 commitment 时的账户订阅控制通道及其 commitment
+    pub account_control_tx: Arc<tokio::sync::Mutex<Option<AccountControl>>>,
+    /// 最近收到的 slot
+    pub last_slot: Arc<AtomicU64>,
+    /// 存活检测配置
+    pub liveness: Option<LivenessConfig>,
+    /// 按协议与事件类型的统计
+    pub stream_metrics: Arc<StreamMetricsCollector>,
+    /// 解析失败回调
+    pub parse_error_callback: Option<ParseErrorCallback>,
+    /// 基于 block meta 的 slot 缺口检测
+    pub slot_gap_detector: Option<Arc<SlotGapDetector>>,
+    /// 原始更新录制器
+    pub recorder: Option<Arc<EventRecorder>>,
+    /// 当前订阅使用的回调线程池
+    pub callback_pool: Arc<parking_lot::Mutex<Option<Arc<CallbackPool>>>>,
+    /// 通知当前订阅的接收任务优雅退出
+    pub shutdown_tx: Arc<parking_lot::Mutex<Option<watch::Sender<bool>>>>,
+    /// 当前订阅的开始时间
+    pub subscription_started_at: Arc<parking_lot::Mutex<Option<Instant>>>,
 
     pub event_type_filter: Arc<tokio::sync::RwLock<Option<EventTypeFilter>>>,
 }
@@ -77,10 +207,68 @@ impl YellowstoneGrpc {
             active_subscription: Arc::new(AtomicBool::new(false)),
             control_tx: Arc::new(tokio::sync::Mutex::new(None)),
             current_request: Arc::new(tokio::sync::RwLock::new(None)),
+            account_control_tx: Arc::new(tokio::sync::Mutex::new(None)),
+            last_slot: Arc::new(AtomicU64::new(0)),
+            liveness: None,
+            stream_metrics: Arc::new(StreamMetricsCollector::new()),
+            parse_error_callback: None,
+            slot_gap_detector: None,
+            recorder: None,
+            callback_pool: Arc::new(parking_lot::Mutex::new(None)),
+            shutdown_tx: Arc::new(parking_lot::Mutex::new(None)),
+            subscription_started_at: Arc::new(parking_lot::Mutex::new(None)),
             event_type_filter: Arc::new(tokio::sync::RwLock::new(None)),
         })
     }
 
+    /// 启用存活检测：超过 `timeout` 未收到任何消息时触发 `on_stall` 回调
+    pub fn with_liveness_timeout(mut self, timeout: Duration) -> Self {
+        self.liveness.get_or_insert_with(LivenessConfig::default).timeout = timeout;
+        self
+    }
+
+    /// 设置卡顿回调并启用存活检测，未设置超时时使用 `DEFAULT_LIVENESS_TIMEOUT_MS`
+    pub fn on_stall<F>(mut self, on_stall: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.liveness.get_or_insert_with(LivenessConfig::default).on_stall =
+            Some(Arc::new(on_stall));
+        self
+    }
+
+    /// 卡顿时强制重连并启用存活检测，仅对 `subscribe_events_resilient` 生效
+    pub fn with_reconnect_on_stall(mut self, reconnect: bool) -> Self {
+        self.liveness.get_or_insert_with(LivenessConfig::default).reconnect = reconnect;
+        self
+    }
+
+    /// 设置解析失败回调，已知账户类型解码失败或交易解析出错时调用
+    pub fn on_parse_error<F>(mut self, on_parse_error: F) -> Self
+    where
+        F: Fn(ParseError) + Send + Sync + 'static,
+    {
+        self.parse_error_callback = Some(Arc::new(on_parse_error));
+        self
+    }
+
+    /// 启用 slot 缺口检测，block meta 的 slot 跳跃大于 1 时调用 `on_gap`
+    ///
+    /// 需要订阅 block meta（未设置事件过滤器，或过滤器包含 block meta 事件）
+    pub fn with_slot_gap_detector<F>(mut self, on_gap: F) -> Self
+    where
+        F: Fn(SlotGap) + Send + Sync + 'static,
+    {
+        self.slot_gap_detector = Some(Arc::new(SlotGapDetector::new(Arc::new(on_gap))));
+        self
+    }
+
+    /// 把收到的原始 `SubscribeUpdate` 录制到 `recorder`，可用 `EventReplayer` 离线回放
+    pub fn with_recorder(mut self, recorder: Arc<EventRecorder>) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     /// 获取配置
     pub fn get_config(&self) -> &StreamClientConfig {
         &self.config
@@ -101,20 +289,66 @@ impl YellowstoneGrpc {
         MetricsManager::global().print_metrics();
     }
 
+    /// 获取按协议、事件类型统计的快照，以及收到的字节数、解析失败次数和运行时长
+    pub fn metrics_snapshot(&self) -> StreamMetrics {
+        self.stream_metrics.snapshot()
+    }
+
+    /// 获取最近收到的 slot，尚未收到任何更新时为 0
+    pub fn last_seen_slot(&self) -> u64 {
+        self.last_slot.load(Ordering::Relaxed)
+    }
+
     /// 启用或禁用性能监控
     pub fn set_enable_metrics(&mut self, enabled: bool) {
         self.config.enable_metrics = enabled;
     }
 
-    /// 停止当前订阅
-    pub async fn stop(&self) {
-        let mut handle_guard = self.subscription_handle.lock().await;
-        if let Some(handle) = handle_guard.take() {
+    /// 停止当前订阅并返回本客户端的最终统计
+    ///
+    /// 接收任务处理完当前消息后退出，已入队的回调执行完毕后才中止工作任务，
+    /// 两者最多各等待 `DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_MS`。清理完成前订阅仍视为活跃，
+    /// 期间发起的新订阅会返回错误，不会被本次清理覆盖。
+    pub async fn stop(&self) -> StreamSummary {
+        let drain_timeout = Duration::from_millis(DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_MS);
+        if let Some(shutdown_tx) = self.shutdown_tx.lock().take() {
+            let _ = shutdown_tx.send(true);
+        }
+        let handle = self.subscription_handle.lock().await.take();
+        if let Some(mut handle) = handle {
+            handle.finish_streams(drain_timeout).await;
+            let pool = self.callback_pool.lock().clone();
+            if let Some(pool) = pool {
+                if tokio::time::timeout(drain_timeout, pool.drain()).await.is_err() {
+                    log::warn!("Callback pool did not drain within {drain_timeout:?}");
+                }
+            }
             handle.stop();
         }
+        if let Some(recorder) = &self.recorder {
+            if let Err(e) = recorder.flush() {
+                error!("Failed to flush event recorder: {e:?}");
+            }
+        }
         *self.control_tx.lock().await = None;
+        *self.account_control_tx.lock().await = None;
         *self.current_request.write().await = None;
         self.active_subscription.store(false, Ordering::Release);
+
+        let runtime = self
+            .subscription_started_at
+            .lock()
+            .take()
+            .map(|started_at| started_at.elapsed())
+            .unwrap_or_default();
+        let metrics = self.stream_metrics.snapshot();
+        StreamSummary {
+            runtime,
+            events_processed: metrics.events_total,
+            events_by_protocol: metrics.events_by_protocol,
+            last_slot: self.last_seen_slot(),
+            reconnects: metrics.reconnects,
+        }
     }
 
     /// Simplified immediate event subscription (recommended for simple scenarios)
@@ -140,6 +374,40 @@ impl YellowstoneGrpc {
         commitment: Option<CommitmentLevel>,
         callback: F,
     ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        self.subscribe_events_with_commitments(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            CommitmentOverrides::default(),
+            callback,
+        )
+        .await
+    }
+
+    /// Event subscription with separate commitment levels for transactions and accounts
+    ///
+    /// Same as [`Self::subscribe_events_immediate`], but `overrides` may set a different
+    /// commitment for transaction/block meta updates and for account updates. Unset fields fall
+    /// back to `commitment`. Yellowstone only supports one commitment per request, so when the
+    /// two levels differ the account filters are served by a second subscription stream.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_with_commitments<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        overrides: CommitmentOverrides,
+        callback: F,
+    ) -> AnyResult<()>
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
     {
@@ -165,123 +433,252 @@ impl YellowstoneGrpc {
             .subscription_manager
             .subscribe_with_account_request(account_filter, event_type_filter.as_ref());
 
+        let (subscribe_request, account_request) = build_commitment_requests(
+            &self.subscription_manager,
+            transactions,
+            accounts,
+            commitment,
+            &overrides,
+            event_type_filter.as_ref(),
+        );
+
+        // 交易与账户的 commitment 不同时，账户使用独立的订阅流
+        let mut account_subscription = None;
+        if let Some(account_request) = account_request {
+            let (account_tx, account_stream, account_request) =
+                self.subscription_manager.subscribe(account_request).await?;
+            let (account_control_tx, account_control_rx) = mpsc::channel(100);
+            *self.account_control_tx.lock().await =
+                Some((account_control_tx, account_request.commitment));
+            account_subscription = Some((account_tx, account_stream, account_control_rx));
+        }
+
         // 订阅事件
-        let (subscribe_tx, mut stream, subscribe_request) = self
+        let (subscribe_tx, stream, subscribe_request) =
+            self.subscription_manager.subscribe(subscribe_request).await?;
+
+        *self.current_request.write().await = Some(subscribe_request);
+        let (control_tx, control_rx) = mpsc::channel(100);
+        *self.control_tx.lock().await = Some(control_tx);
+
+        let (ctx, callback_workers) =
+            self.stream_context(protocols, bot_wallet, event_type_filter, Arc::new(callback));
+        let account_handle =
+            account_subscription.map(|(account_tx, account_stream, account_rx)| {
+                spawn_stream_task(account_tx, account_stream, account_rx, ctx.clone())
+            });
+        let stream_handle = spawn_stream_task(subscribe_tx, stream, control_rx, ctx);
+
+        // 保存订阅句柄
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, account_handle, metrics_handle)
+                .with_callback_workers(callback_workers);
+        let mut handle_guard = self.subscription_handle.lock().await;
+        *handle_guard = Some(subscription_handle);
+
+        Ok(())
+    }
+
+    /// Event subscription that reconnects automatically
+    ///
+    /// Behaves like [`Self::subscribe_events_immediate`], but when the stream errors or ends it
+    /// reconnects with exponential backoff (see [`StreamClientConfig::reconnect`]) and re-issues
+    /// the current filters, including changes made through [`Self::update_subscription`].
+    /// `status_callback` is notified of connection state changes; the last seen slot is reported
+    /// with each disconnect so callers can detect gaps, and is also available from
+    /// [`Self::last_seen_slot`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_resilient<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+        status_callback: Option<StatusCallback>,
+    ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        *self.event_type_filter.write().await = event_type_filter.clone();
+        if self
+            .active_subscription
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(anyhow!("Already subscribed. Use update_subscription() to modify filters"));
+        }
+
+        let mut metrics_handle = None;
+        // 启动自动性能监控（如果启用）
+        if self.config.enable_metrics {
+            metrics_handle = MetricsManager::global().start_auto_monitoring().await;
+        }
+
+        let transactions = self
+            .subscription_manager
+            .get_subscribe_request_filter(transaction_filter, event_type_filter.as_ref());
+        let accounts = self
             .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.as_ref())
-            .await?;
+            .subscribe_with_account_request(account_filter, event_type_filter.as_ref());
+        let subscribe_request = self.subscription_manager.build_subscribe_request(
+            transactions,
+            accounts,
+            commitment,
+            event_type_filter.as_ref(),
+        );
 
-        // 用 Arc<Mutex<>> 包装 subscribe_tx 以支持多线程共享
-        let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
+        // 首次连接失败直接返回错误
+        let (subscribe_tx, stream, subscribe_request) =
+            self.subscription_manager.subscribe(subscribe_request).await?;
         *self.current_request.write().await = Some(subscribe_request);
-        let (control_tx, mut control_rx) = mpsc::channel(100);
+        let (control_tx, control_rx) = mpsc::channel(100);
         *self.control_tx.lock().await = Some(control_tx);
 
-        // Wrap callback once before the async block
-        let callback = Arc::new(callback);
-
-        let stream_handle = tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    message = stream.next() => {
-                        match message {
-                            Some(Ok(msg)) => {
-                                let created_at = msg.created_at;
-                                match msg.update_oneof {
-                                    Some(UpdateOneof::Account(account)) => {
-                                        let account_pretty = factory::create_account_pretty_pooled(account);
-                                        log::debug!("Received account: {:?}", account_pretty);
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::Account(account_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing account event: {e:?}");
-                                        }
-                                    }
-                                    Some(UpdateOneof::BlockMeta(sut)) => {
-                                        let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
-                                        log::debug!("Received block meta: {:?}", block_meta_pretty);
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::BlockMeta(block_meta_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing block meta event: {e:?}");
-                                        }
-                                    }
-                                    Some(UpdateOneof::Transaction(sut)) => {
-                                        let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
-                                        log::debug!(
-                                            "Received transaction: {} at slot {}",
-                                            transaction_pretty.signature,
-                                            transaction_pretty.slot
-                                        );
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::Transaction(transaction_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing transaction event: {e:?}");
-                                        }
-                                    }
-                                    Some(UpdateOneof::Ping(_)) => {
-                                        // 只在需要时获取锁，并立即释放
-                                        if let Ok(mut tx_guard) = subscribe_tx.try_lock() {
-                                            let _ = tx_guard
-                                                .send(SubscribeRequest {
-                                                    ping: Some(SubscribeRequestPing { id: 1 }),
-                                                    ..Default::default()
-                                                })
-                                                .await;
-                                        }
-                                        log::debug!("service is ping: {}", Local::now());
-                                    }
-                                    Some(UpdateOneof::Pong(_)) => {
-                                        log::debug!("service is pong: {}", Local::now());
-                                    }
-                                    _ => {
-                                        log::debug!("Received other message type");
-                                    }
-                                }
-                            }
-                            Some(Err(error)) => {
-                                error!("Stream error: {error:?}");
-                                break;
-                            }
-                            None => break,
-                        }
-                    }
-                    Some(update) = control_rx.next() => {
-                        if let Err(e) = subscribe_tx.lock().await.send(update).await {
-                            error!("Failed to send subscription update: {}", e);
-                            break;
-                        }
-                    }
-                }
+        let notify = move |status: ConnectionStatus| {
+            if let Some(status_callback) = &status_callback {
+                status_callback(status);
             }
-        });
+        };
+        notify(ConnectionStatus::Connected);
+
+        let (mut ctx, callback_workers) =
+            self.stream_context(protocols, bot_wallet, event_type_filter, Arc::new(callback));
+        ctx.reconnect_on_stall = self.liveness.as_ref().is_some_and(|l| l.reconnect);
+        let subscription_manager = self.subscription_manager.clone();
+        let current_request = self.current_request.clone();
+        let connect = move || {
+            let subscription_manager = subscription_manager.clone();
+            let current_request = current_request.clone();
+            async move {
+                // stop 后 current_request 被清空，此时不再重连
+                let request = current_request.read().await.clone()?;
+                Some(
+                    subscription_manager
+                        .subscribe(request)
+                        .await
+                        .map(|(tx, stream, _)| (tx, stream)),
+                )
+            }
+        };
+        let stream_handle = tokio::spawn(run_resilient_loop(
+            subscribe_tx,
+            stream,
+            control_rx,
+            ctx,
+            self.config.reconnect.clone(),
+            self.active_subscription.clone(),
+            notify,
+            connect,
+        ));
 
         // 保存订阅句柄
-        let subscription_handle = SubscriptionHandle::new(stream_handle, None, metrics_handle);
+        let subscription_handle = SubscriptionHandle::new(stream_handle, None, metrics_handle)
+            .with_callback_workers(callback_workers);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
         Ok(())
     }
 
+    /// 构建订阅流上下文，按配置启动回调线程池
+    fn stream_context(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> (StreamContext, Option<CallbackWorkers>) {
+        let stream_metrics = self.stream_metrics.clone();
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(move |event: DexEvent| {
+            stream_metrics.record_event(&event);
+            callback(event);
+        });
+        // 解析失败总是计入统计，再转交给用户回调
+        let stream_metrics = self.stream_metrics.clone();
+        let on_parse_error = self.parse_error_callback.clone();
+        let parse_error_callback: ParseErrorCallback = Arc::new(move |error: ParseError| {
+            stream_metrics.record_parse_failure();
+            if let Some(on_parse_error) = &on_parse_error {
+                on_parse_error(error);
+            }
+        });
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        *self.shutdown_tx.lock() = Some(shutdown_tx);
+        *self.subscription_started_at.lock() = Some(Instant::now());
+        let mut ctx = StreamContext {
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            callback,
+            last_slot: self.last_slot.clone(),
+            liveness: self.liveness.clone(),
+            reconnect_on_stall: false,
+            stream_metrics: self.stream_metrics.clone(),
+            parse_error_callback: Some(parse_error_callback),
+            slot_gap_detector: self.slot_gap_detector.clone(),
+            recorder: self.recorder.clone(),
+            callback_pool: None,
+            pending: Arc::new(parking_lot::Mutex::new(Vec::new())),
+            shutdown: shutdown_rx,
+        };
+        let mut callback_workers = None;
+        if let Some(pool_config) = &self.config.callback_pool {
+            let (pool, handles) = CallbackPool::spawn(pool_config, ctx.callback.clone());
+            // 接收路径只收集事件，随后由 flush_pending 送入线程池
+            let pending = ctx.pending.clone();
+            ctx.callback = Arc::new(move |event| pending.lock().push(event));
+            ctx.callback_pool = Some(pool.clone());
+            *self.callback_pool.lock() = Some(pool.clone());
+            callback_workers = Some((pool, handles));
+        }
+        (ctx, callback_workers)
+    }
+
+    /// 获取回调线程池的队列指标，未启用线程池时返回 None
+    pub fn callback_pool_metrics(&self) -> Option<CallbackPoolMetrics> {
+        self.callback_pool.lock().as_ref().map(|pool| pool.metrics())
+    }
+
+    /// Replay historical transactions through the event parser
+    ///
+    /// Pages through `getSignaturesForAddress` for each program id and fetches every successful
+    /// transaction in `[from_slot, to_slot]` with `getTransaction`, at most
+    /// `config.backfill.concurrency` requests at a time. Events are delivered to `callback`
+    /// oldest first, exactly as the live stream would, so detectors can be seeded from history.
+    ///
+    /// # Returns
+    /// Returns the number of transactions processed
+    pub async fn backfill_transactions<F>(
+        &self,
+        rpc_client: &RpcClient,
+        program_ids: Vec<Pubkey>,
+        from_slot: u64,
+        to_slot: u64,
+        callback: F,
+    ) -> AnyResult<usize>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let stream_metrics = self.stream_metrics.clone();
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(move |event: DexEvent| {
+            stream_metrics.record_event(&event);
+            callback(event);
+        });
+        backfill_transactions(
+            rpc_client,
+            &self.config.backfill,
+            &program_ids,
+            from_slot,
+            to_slot,
+            self.event_type_filter.read().await.as_ref(),
+            callback,
+        )
+        .await
+    }
+
     /// Update subscription filters at runtime without reconnection
     ///
     /// # Parameters
@@ -332,6 +729,20 @@ impl YellowstoneGrpc {
             )
             .unwrap_or_default();
 
+        // 账户走独立订阅流时，把账户过滤器发往该流
+        let account_sender = self.account_control_tx.lock().await.clone();
+        if let Some((mut account_sender, account_commitment)) = account_sender {
+            let account_request = SubscribeRequest {
+                accounts: std::mem::take(&mut request.accounts),
+                commitment: account_commitment,
+                ..Default::default()
+            };
+            account_sender
+                .send(account_request)
+                .await
+                .map_err(|e| anyhow!("Failed to send account update: {}", e))?;
+        }
+
         control_sender
             .send(request.clone())
             .await
@@ -356,6 +767,657 @@ impl Clone for YellowstoneGrpc {
             control_tx: self.control_tx.clone(),
             event_type_filter: self.event_type_filter.clone(),
             current_request: self.current_request.clone(),
+            account_control_tx: self.account_control_tx.clone(),
+            last_slot: self.last_slot.clone(),
+            liveness: self.liveness.clone(),
+            stream_metrics: self.stream_metrics.clone(),
+            parse_error_callback: self.parse_error_callback.clone(),
+            slot_gap_detector: self.slot_gap_detector.clone(),
+            recorder: self.recorder.clone(),
+            callback_pool: self.callback_pool.clone(),
+            shutdown_tx: self.shutdown_tx.clone(),
+            subscription_started_at: self.subscription_started_at.clone(),
+        }
+    }
+}
+
+/// 订阅流处理任务共享的上下文
+#[derive(Clone)]
+struct StreamContext {
+    protocols: Vec<Protocol>,
+    bot_wallet: Option<Pubkey>,
+    event_type_filter: Option<EventTypeFilter>,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    last_slot: Arc<AtomicU64>,
+    liveness: Option<LivenessConfig>,
+    reconnect_on_stall: bool,
+    stream_metrics: Arc<StreamMetricsCollector>,
+    parse_error_callback: Option<ParseErrorCallback>,
+    slot_gap_detector: Option<Arc<SlotGapDetector>>,
+    recorder: Option<Arc<EventRecorder>>,
+    callback_pool: Option<Arc<CallbackPool>>,
+    pending: Arc<parking_lot::Mutex<Vec<DexEvent>>>,
+    shutdown: watch::Receiver<bool>,
+}
+
+impl StreamContext {
+    /// 把接收路径上收集的事件送入回调线程池
+    async fn flush_pending(&self) {
+        let Some(pool) = &self.callback_pool else {
+            return;
+        };
+        let events = std::mem::take(&mut *self.pending.lock());
+        for event in events {
+            pool.push(event).await;
+        }
+    }
+}
+
+/// 构建主订阅请求；账户与交易的 commitment 不同时，账户改用独立的订阅请求
+fn build_commitment_requests(
+    subscription_manager: &SubscriptionManager,
+    transactions: Option<TransactionsFilterMap>,
+    accounts: Option<AccountsFilterMap>,
+    commitment: Option<CommitmentLevel>,
+    overrides: &CommitmentOverrides,
+    event_type_filter: Option<&EventTypeFilter>,
+) -> (SubscribeRequest, Option<SubscribeRequest>) {
+    let (transaction_commitment, account_commitment) = overrides.resolve(commitment);
+    if transaction_commitment == account_commitment || accounts.is_none() {
+        let request = subscription_manager.build_subscribe_request(
+            transactions,
+            accounts,
+            transaction_commitment,
+            event_type_filter,
+        );
+        return (request, None);
+    }
+
+    let mut account_request = subscription_manager.build_subscribe_request(
+        None,
+        accounts,
+        account_commitment,
+        event_type_filter,
+    );
+    account_request.blocks_meta.clear();
+    let request = subscription_manager.build_subscribe_request(
+        transactions,
+        None,
+        transaction_commitment,
+        event_type_filter,
+    );
+    (request, Some(account_request))
+}
+
+/// 启动订阅流的处理任务
+fn spawn_stream_task<T, S>(
+    subscribe_tx: T,
+    stream: S,
+    mut control_rx: mpsc::Receiver<SubscribeRequest>,
+    ctx: StreamContext,
+) -> JoinHandle<()>
+where
+    T: Sink<SubscribeRequest, Error = mpsc::SendError> + Unpin + Send + 'static,
+    S: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin + Send + 'static,
+{
+    tokio::spawn(async move { run_stream_loop(subscribe_tx, stream, &mut control_rx, &ctx).await })
+}
+
+/// 重连循环：订阅流结束后按退避重连并重新下发当前请求，直到 stop 或超过最大重连次数
+///
+/// `connect` 返回 None 表示已没有可用的订阅请求，循环随之结束。
+#[allow(clippy::too_many_arguments)]
+async fn run_resilient_loop<T, S, C, Fut>(
+    subscribe_tx: T,
+    stream: S,
+    mut control_rx: mpsc::Receiver<SubscribeRequest>,
+    ctx: StreamContext,
+    reconnect: ReconnectConfig,
+    active_subscription: Arc<AtomicBool>,
+    notify: impl Fn(ConnectionStatus),
+    mut connect: C,
+) where
+    T: Sink<SubscribeRequest, Error = mpsc::SendError> + Unpin + Send + 'static,
+    S: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin + Send + 'static,
+    C: FnMut() -> Fut,
+    Fut: Future<Output = Option<AnyResult<(T, S)>>>,
+{
+    let mut shutdown = ctx.shutdown.clone();
+    run_stream_loop(subscribe_tx, stream, &mut control_rx, &ctx).await;
+    let mut attempt = 0u32;
+    loop {
+        // stop 导致的退出不再通知状态变化
+        if *shutdown.borrow() || !active_subscription.load(Ordering::Acquire) {
+            break;
+        }
+        let last_slot = ctx.last_slot.load(Ordering::Relaxed);
+        if attempt == 0 {
+            notify(ConnectionStatus::Disconnected { last_slot });
+        }
+        attempt += 1;
+        if reconnect.max_attempts.is_some_and(|max| attempt > max) {
+            error!("Giving up after {} reconnect attempts", attempt - 1);
+            active_subscription.store(false, Ordering::Release);
+            break;
+        }
+        notify(ConnectionStatus::Reconnecting { attempt, last_slot });
+        // 退避与建连期间调用 stop 时立即退出
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            _ = tokio::time::sleep(reconnect.backoff(attempt)) => {}
+        }
+        let connected = tokio::select! {
+            _ = shutdown.changed() => break,
+            connected = connect() => connected,
+        };
+        let Some(connected) = connected else {
+            break;
+        };
+        match connected {
+            Ok((subscribe_tx, stream)) => {
+                attempt = 0;
+                ctx.stream_metrics.record_reconnect();
+                notify(ConnectionStatus::Connected);
+                run_stream_loop(subscribe_tx, stream, &mut control_rx, &ctx).await;
+            }
+            Err(e) => {
+                error!("Reconnect attempt {attempt} failed: {e:?}");
+            }
+        }
+    }
+}
+
+/// 处理订阅流直到出错或结束
+async fn run_stream_loop<T, S>(
+    subscribe_tx: T,
+    mut stream: S,
+    control_rx: &mut mpsc::Receiver<SubscribeRequest>,
+    ctx: &StreamContext,
+) where
+    T: Sink<SubscribeRequest, Error = mpsc::SendError> + Unpin + Send + 'static,
+    S: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin + Send + 'static,
+{
+    // 用 Arc<Mutex<>> 包装 subscribe_tx 以支持多线程共享
+    let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
+    let liveness_timeout = ctx.liveness.as_ref().map(|l| l.timeout);
+    let mut deadline = liveness_timeout.map(|timeout| Instant::now() + timeout);
+    let mut shutdown = ctx.shutdown.clone();
+    loop {
+        if *shutdown.borrow_and_update() {
+            break;
+        }
+        let stall = async {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            changed = shutdown.changed() => {
+                // 发送端被丢弃时同样视为停止
+                if changed.is_err() {
+                    break;
+                }
+            }
+            _ = stall => {
+                let timeout = liveness_timeout.unwrap_or_default();
+                log::warn!("No message received for {timeout:?}");
+                if let Some(on_stall) = ctx.liveness.as_ref().and_then(|l| l.on_stall.as_ref()) {
+                    on_stall(timeout);
+                }
+                if ctx.reconnect_on_stall {
+                    break;
+                }
+                deadline = Some(Instant::now() + timeout);
+            }
+            message = stream.next() => {
+                if let Some(timeout) = liveness_timeout {
+                    deadline = Some(Instant::now() + timeout);
+                }
+                match message {
+                    Some(Ok(msg)) => {
+                        ctx.stream_metrics.record_message(msg.encoded_len());
+                        if let Some(recorder) = &ctx.recorder {
+                            if let Err(e) = recorder.record(&msg) {
+                                error!("Failed to record update: {e:?}");
+                            }
+                        }
+                        let created_at = msg.created_at;
+                        match msg.update_oneof {
+                            Some(UpdateOneof::Account(account)) => {
+                                let account_pretty = factory::create_account_pretty_pooled(account);
+                                ctx.last_slot.fetch_max(account_pretty.slot, Ordering::Relaxed);
+                                log::debug!("Received account: {:?}", account_pretty);
+                                if let Err(e) = process_grpc_transaction(
+                                    EventPretty::Account(account_pretty),
+                                    &ctx.protocols,
+                                    ctx.event_type_filter.as_ref(),
+                                    ctx.callback.clone(),
+                                    ctx.bot_wallet,
+                                    ctx.parse_error_callback.as_ref(),
+                                )
+                                .await
+                                {
+                                    error!("Error processing account event: {e:?}");
+                                }
+                            }
+                            Some(UpdateOneof::BlockMeta(sut)) => {
+                                let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
+                                ctx.last_slot.fetch_max(block_meta_pretty.slot, Ordering::Relaxed);
+                                if let Some(slot_gap_detector) = &ctx.slot_gap_detector {
+                                    slot_gap_detector.record(block_meta_pretty.slot);
+                                }
+                                log::debug!("Received block meta: {:?}", block_meta_pretty);
+                                if let Err(e) = process_grpc_transaction(
+                                    EventPretty::BlockMeta(block_meta_pretty),
+                                    &ctx.protocols,
+                                    ctx.event_type_filter.as_ref(),
+                                    ctx.callback.clone(),
+                                    ctx.bot_wallet,
+                                    ctx.parse_error_callback.as_ref(),
+                                )
+                                .await
+                                {
+                                    error!("Error processing block meta event: {e:?}");
+                                }
+                            }
+                            Some(UpdateOneof::Transaction(sut)) => {
+                                let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
+                                ctx.last_slot.fetch_max(transaction_pretty.slot, Ordering::Relaxed);
+                                log::debug!(
+                                    "Received transaction: {} at slot {}",
+                                    transaction_pretty.signature,
+                                    transaction_pretty.slot
+                                );
+                                if let Err(e) = process_grpc_transaction(
+                                    EventPretty::Transaction(transaction_pretty),
+                                    &ctx.protocols,
+                                    ctx.event_type_filter.as_ref(),
+                                    ctx.callback.clone(),
+                                    ctx.bot_wallet,
+                                    ctx.parse_error_callback.as_ref(),
+                                )
+                                .await
+                                {
+                                    error!("Error processing transaction event: {e:?}");
+                                }
+                            }
+                            Some(UpdateOneof::Ping(_)) => {
+                                // 只在需要时获取锁，并立即释放
+                                if let Ok(mut tx_guard) = subscribe_tx.try_lock() {
+                                    let _ = tx_guard
+                                        .send(SubscribeRequest {
+                                            ping: Some(SubscribeRequestPing { id: 1 }),
+                                            ..Default::default()
+                                        })
+                                        .await;
+                                }
+                                log::debug!("service is ping: {}", Local::now());
+                            }
+                            Some(UpdateOneof::Pong(_)) => {
+                                log::debug!("service is pong: {}", Local::now());
+                            }
+                            _ => {
+                                log::debug!("Received other message type");
+                            }
+                        }
+                        ctx.flush_pending().await;
+                    }
+                    Some(Err(error)) => {
+                        error!("Stream error: {error:?}");
+                        break;
+                    }
+                    None => break,
+                }
+            }
+            Some(update) = control_rx.next() => {
+                if let Err(e) = subscribe_tx.lock().await.send(update).await {
+                    error!("Failed to send subscription update: {}", e);
+                    break;
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::geyser::SubscribeUpdateBlockMeta;
+
+    fn subscription_manager() -> SubscriptionManager {
+        SubscriptionManager::new(
+            "http://127.0.0.1:10000".to_string(),
+            None,
+            StreamClientConfig::default(),
+        )
+    }
+
+    fn filters(
+        manager: &SubscriptionManager,
+    ) -> (Option<TransactionsFilterMap>, Option<AccountsFilterMap>) {
+        let program = Pubkey::new_unique().to_string();
+        let transactions = manager.get_subscribe_request_filter(
+            vec![TransactionFilter {
+                account_include: vec![program.clone()],
+                account_exclude: vec![],
+                account_required: vec![],
+            }],
+            None,
+        );
+        let accounts = manager.subscribe_with_account_request(
+            vec![AccountFilter { account: vec![], owner: vec![program], filters: vec![] }],
+            None,
+        );
+        (transactions, accounts)
+    }
+
+    #[tokio::test]
+    async fn silent_stream_triggers_stall_and_reconnect() {
+        let stalls = Arc::new(AtomicU64::new(0));
+        let stall_counter = stalls.clone();
+        // on_stall 在 with_liveness_timeout 之前调用也必须生效
+        let grpc = YellowstoneGrpc::new("http://127.0.0.1:10000".to_string(), None)
+            .unwrap()
+            .on_stall(move |_| {
+                stall_counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .with_reconnect_on_stall(true)
+            .with_liveness_timeout(Duration::from_millis(50));
+        let (mut ctx, _) = grpc.stream_context(vec![], None, None, Arc::new(|_| {}));
+        ctx.reconnect_on_stall = grpc.liveness.as_ref().is_some_and(|l| l.reconnect);
+
+        let (subscribe_tx, _subscribe_rx) = mpsc::channel(1);
+        let (_control_tx, mut control_rx) = mpsc::channel(1);
+        let stream = futures::stream::pending::<Result<SubscribeUpdate, Status>>();
+
+        // 卡顿且要求重连时，接收循环应返回以便上层重连
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            run_stream_loop(subscribe_tx, stream, &mut control_rx, &ctx),
+        )
+        .await
+        .expect("stream loop should exit after the stall");
+        assert_eq!(stalls.load(Ordering::Relaxed), 1);
+    }
+
+    type MockStream = std::pin::Pin<Box<dyn Stream<Item = Result<SubscribeUpdate, Status>> + Send>>;
+
+    fn block_meta_updates(slots: std::ops::Range<u64>) -> Vec<Result<SubscribeUpdate, Status>> {
+        slots
+            .map(|slot| {
+                Ok(SubscribeUpdate {
+                    update_oneof: Some(UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+                        slot,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn status_recorder(
+    ) -> (Arc<parking_lot::Mutex<Vec<ConnectionStatus>>>, impl Fn(ConnectionStatus)) {
+        let statuses = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let recorded = statuses.clone();
+        (statuses, move |status| recorded.lock().push(status))
+    }
+
+    #[tokio::test]
+    async fn resilient_loop_reconnects_after_the_stream_closes() {
+        let grpc = YellowstoneGrpc::new("http://127.0.0.1:10000".to_string(), None).unwrap();
+        grpc.active_subscription.store(true, Ordering::Release);
+        let (ctx, _) = grpc.stream_context(vec![], None, None, Arc::new(|_| {}));
+        let (statuses, notify) = status_recorder();
+        let reconnect =
+            ReconnectConfig { initial_backoff_ms: 1, max_backoff_ms: 1, max_attempts: None };
+
+        // 第一条流发送两个更新后关闭，重连后的流继续发送
+        let first: MockStream = Box::pin(futures::stream::iter(block_meta_updates(100..102)));
+        let connect = || async {
+            let stream: MockStream = Box::pin(
+                futures::stream::iter(block_meta_updates(102..104))
+                    .chain(futures::stream::pending()),
+            );
+            Some(Ok((mpsc::channel(1).0, stream)))
+        };
+        let (_control_tx, control_rx) = mpsc::channel(1);
+        let handle = tokio::spawn(run_resilient_loop(
+            mpsc::channel(1).0,
+            first,
+            control_rx,
+            ctx,
+            reconnect,
+            grpc.active_subscription.clone(),
+            notify,
+            connect,
+        ));
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while grpc.stream_metrics.snapshot().events_total < 4 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("events should keep arriving after the reconnect");
+
+        let summary = grpc.stop().await;
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("loop should exit on stop")
+            .unwrap();
+        assert_eq!(
+            *statuses.lock(),
+            vec![
+                ConnectionStatus::Disconnected { last_slot: 101 },
+                ConnectionStatus::Reconnecting { attempt: 1, last_slot: 101 },
+                ConnectionStatus::Connected,
+            ]
+        );
+        assert_eq!(summary.events_processed, 4);
+        assert_eq!(summary.last_slot, 103);
+        assert_eq!(summary.reconnects, 1);
+    }
+
+    #[tokio::test]
+    async fn resilient_loop_exits_promptly_when_stopped_during_backoff() {
+        let grpc = YellowstoneGrpc::new("http://127.0.0.1:10000".to_string(), None).unwrap();
+        grpc.active_subscription.store(true, Ordering::Release);
+        let (ctx, _) = grpc.stream_context(vec![], None, None, Arc::new(|_| {}));
+        let (statuses, notify) = status_recorder();
+        let reconnect = ReconnectConfig {
+            initial_backoff_ms: 30_000,
+            max_backoff_ms: 30_000,
+            max_attempts: None,
+        };
+
+        let first: MockStream = Box::pin(futures::stream::empty());
+        let connect = || async { None::<AnyResult<(mpsc::Sender<SubscribeRequest>, MockStream)>> };
+        let (_control_tx, control_rx) = mpsc::channel(1);
+        let handle = tokio::spawn(run_resilient_loop(
+            mpsc::channel(1).0,
+            first,
+            control_rx,
+            ctx,
+            reconnect,
+            grpc.active_subscription.clone(),
+            notify,
+            connect,
+        ));
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while statuses.lock().len() < 2 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("loop should start backing off");
+        let started = std::time::Instant::now();
+        grpc.stop().await;
+        handle.await.unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            *statuses.lock(),
+            vec![
+                ConnectionStatus::Disconnected { last_slot: 0 },
+                ConnectionStatus::Reconnecting { attempt: 1, last_slot: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn commitment_requests_fall_back_to_request_commitment() {
+        let manager = subscription_manager();
+        let (transactions, accounts) = filters(&manager);
+
+        let (request, account_request) = build_commitment_requests(
+            &manager,
+            transactions,
+            accounts,
+            Some(CommitmentLevel::Finalized),
+            &CommitmentOverrides::default(),
+            None,
+        );
+
+        assert!(account_request.is_none());
+        assert_eq!(request.commitment, Some(CommitmentLevel::Finalized as i32));
+        assert_eq!(request.transactions.len(), 1);
+        assert_eq!(request.accounts.len(), 1);
+    }
+
+    #[test]
+    fn commitment_requests_split_accounts_with_different_commitment() {
+        let manager = subscription_manager();
+        let (transactions, accounts) = filters(&manager);
+
+        let (request, account_request) = build_commitment_requests(
+            &manager,
+            transactions,
+            accounts,
+            Some(CommitmentLevel::Confirmed),
+            &CommitmentOverrides { transactions: None, accounts: Some(CommitmentLevel::Processed) },
+            None,
+        );
+
+        assert_eq!(request.commitment, Some(CommitmentLevel::Confirmed as i32));
+        assert_eq!(request.transactions.len(), 1);
+        assert!(request.accounts.is_empty());
+        assert!(!request.blocks_meta.is_empty());
+
+        let account_request = account_request.expect("accounts should use a separate request");
+        assert_eq!(account_request.commitment, Some(CommitmentLevel::Processed as i32));
+        assert_eq!(account_request.accounts.len(), 1);
+        assert!(account_request.transactions.is_empty());
+        assert!(account_request.blocks_meta.is_empty());
+    }
+
+    #[test]
+    fn commitment_requests_do_not_split_when_commitments_match_or_no_accounts() {
+        let manager = subscription_manager();
+        let (transactions, accounts) = filters(&manager);
+        let (request, account_request) = build_commitment_requests(
+            &manager,
+            transactions,
+            accounts,
+            Some(CommitmentLevel::Confirmed),
+            &CommitmentOverrides { transactions: None, accounts: Some(CommitmentLevel::Confirmed) },
+            None,
+        );
+        assert!(account_request.is_none());
+        assert_eq!(request.accounts.len(), 1);
+
+        let (transactions, _) = filters(&manager);
+        let (request, account_request) = build_commitment_requests(
+            &manager,
+            transactions,
+            None,
+            Some(CommitmentLevel::Confirmed),
+            &CommitmentOverrides { transactions: None, accounts: Some(CommitmentLevel::Processed) },
+            None,
+        );
+        assert!(account_request.is_none());
+        assert_eq!(request.commitment, Some(CommitmentLevel::Confirmed as i32));
+    }
+
+    #[test]
+    fn account_filters_reach_the_subscribe_request() {
+        let manager = subscription_manager();
+        let owner = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique();
+        let accounts = manager.subscribe_with_account_request(
+            vec![AccountFilter {
+                account: vec![],
+                owner: vec![owner.clone()],
+                filters: vec![
+                    AccountFilter::memcmp(0, mint.to_bytes()),
+                    AccountFilter::data_size(165),
+                ],
+            }],
+            None,
+        );
+        let request = manager.build_subscribe_request(None, accounts, None, None);
+
+        let account = &request.accounts["account_0"];
+        assert_eq!(account.owner, vec![owner]);
+        assert_eq!(
+            account.filters,
+            vec![
+                SubscribeRequestFilterAccountsFilter {
+                    filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                        offset: 0,
+                        data: Some(Data::Bytes(mint.to_bytes().to_vec())),
+                    })),
+                },
+                SubscribeRequestFilterAccountsFilter { filter: Some(Filter::Datasize(165)) },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stop_drains_stream_and_reports_summary() {
+        let grpc = YellowstoneGrpc::new("http://127.0.0.1:10000".to_string(), None).unwrap();
+        // 订阅晚于客户端创建开始，runtime 不应包含这段时间
+        let idle_before_subscribe = Duration::from_millis(100);
+        tokio::time::sleep(idle_before_subscribe).await;
+        grpc.active_subscription.store(true, Ordering::Release);
+        let (ctx, callback_workers) = grpc.stream_context(vec![], None, None, Arc::new(|_| {}));
+
+        let updates: Vec<Result<SubscribeUpdate, Status>> = (100..103)
+            .map(|slot| {
+                Ok(SubscribeUpdate {
+                    update_oneof: Some(UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+                        slot,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let stream = futures::stream::iter(updates).chain(futures::stream::pending());
+        let (subscribe_tx, _subscribe_rx) = mpsc::channel(1);
+        let (_control_tx, control_rx) = mpsc::channel(1);
+        let stream_handle = spawn_stream_task(subscribe_tx, stream, control_rx, ctx);
+        *grpc.subscription_handle.lock().await = Some(
+            SubscriptionHandle::new(stream_handle, None, None).with_callback_workers(callback_workers),
+        );
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while grpc.stream_metrics.snapshot().events_total < 3 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("events should be processed");
+
+        let summary = grpc.stop().await;
+        assert_eq!(summary.events_processed, 3);
+        assert_eq!(summary.events_by_protocol.get(&ProtocolType::Common), Some(&3));
+        assert_eq!(summary.last_slot, 102);
+        assert_eq!(summary.reconnects, 0);
+        assert!(summary.runtime + idle_before_subscribe <= grpc.metrics_snapshot().uptime);
+        assert!(!grpc.active_subscription.load(Ordering::Acquire));
+        assert!(grpc.subscription_handle.lock().await.is_none());
+        assert_eq!(grpc.stop().await.runtime, Duration::ZERO);
+    }
+}
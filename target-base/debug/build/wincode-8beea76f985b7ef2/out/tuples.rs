@@ -0,0 +1 @@
+impl < A , B > crate :: SchemaWrite for (A , B) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , { type Src = (A :: Src , B :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; Ok (()) } } impl < 'de , A , B > crate :: SchemaRead < 'de > for (A , B) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B > { init_count : u8 , dst_ptr : * mut (A , B) , } impl < A , B > Drop for DropGuard < A , B > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C > crate :: SchemaWrite for (A , B , C) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; Ok (()) } } impl < 'de , A , B , C > crate :: SchemaRead < 'de > for (A , B , C) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C > { init_count : u8 , dst_ptr : * mut (A , B , C) , } impl < A , B , C > Drop for DropGuard < A , B , C > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D > crate :: SchemaWrite for (A , B , C , D) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; Ok (()) } } impl < 'de , A , B , C , D > crate :: SchemaRead < 'de > for (A , B , C , D) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D > { init_count : u8 , dst_ptr : * mut (A , B , C , D) , } impl < A , B , C , D > Drop for DropGuard < A , B , C , D > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E > crate :: SchemaWrite for (A , B , C , D , E) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; Ok (()) } } impl < 'de , A , B , C , D , E > crate :: SchemaRead < 'de > for (A , B , C , D , E) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E) , } impl < A , B , C , D , E > Drop for DropGuard < A , B , C , D , E > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F > crate :: SchemaWrite for (A , B , C , D , E , F) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F > crate :: SchemaRead < 'de > for (A , B , C , D , E , F) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F) , } impl < A , B , C , D , E , F > Drop for DropGuard < A , B , C , D , E , F > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G > crate :: SchemaWrite for (A , B , C , D , E , F , G) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G) , } impl < A , B , C , D , E , F , G > Drop for DropGuard < A , B , C , D , E , F , G > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H > crate :: SchemaWrite for (A , B , C , D , E , F , G , H) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H) , } impl < A , B , C , D , E , F , G , H > Drop for DropGuard < A , B , C , D , E , F , G , H > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I) , } impl < A , B , C , D , E , F , G , H , I > Drop for DropGuard < A , B , C , D , E , F , G , H , I > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J) , } impl < A , B , C , D , E , F , G , H , I , J > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K) , } impl < A , B , C , D , E , F , G , H , I , J , K > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K , L > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K , L) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , L : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , L :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src , L :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ? + < L as crate :: SchemaWrite > :: size_of (& value . 11) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; < L as crate :: SchemaWrite > :: write (writer , & value . 11) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K , L > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K , L) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , L : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst , L :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K , L > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K , L) , } impl < A , B , C , D , E , F , G , H , I , J , K , L > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K , L > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 11u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; * init_count += 1 ; < L as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 11) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K , L , M > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K , L , M) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , L : crate :: SchemaWrite , M : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , L :: Src : Sized , M :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src , L :: Src , M :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ? + < L as crate :: SchemaWrite > :: size_of (& value . 11) ? + < M as crate :: SchemaWrite > :: size_of (& value . 12) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; < L as crate :: SchemaWrite > :: write (writer , & value . 11) ? ; < M as crate :: SchemaWrite > :: write (writer , & value . 12) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K , L , M > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K , L , M) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , L : crate :: SchemaRead < 'de > , M : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst , L :: Dst , M :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K , L , M) , } impl < A , B , C , D , E , F , G , H , I , J , K , L , M > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 11u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 12u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; * init_count += 1 ; < L as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 11) . cast () }) ? ; * init_count += 1 ; < M as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 12) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K , L , M , N) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , L : crate :: SchemaWrite , M : crate :: SchemaWrite , N : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , L :: Src : Sized , M :: Src : Sized , N :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src , L :: Src , M :: Src , N :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ? + < L as crate :: SchemaWrite > :: size_of (& value . 11) ? + < M as crate :: SchemaWrite > :: size_of (& value . 12) ? + < N as crate :: SchemaWrite > :: size_of (& value . 13) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; < L as crate :: SchemaWrite > :: write (writer , & value . 11) ? ; < M as crate :: SchemaWrite > :: write (writer , & value . 12) ? ; < N as crate :: SchemaWrite > :: write (writer , & value . 13) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K , L , M , N > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K , L , M , N) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , L : crate :: SchemaRead < 'de > , M : crate :: SchemaRead < 'de > , N : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst , L :: Dst , M :: Dst , N :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K , L , M , N) , } impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 11u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 12u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 13u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; * init_count += 1 ; < L as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 11) . cast () }) ? ; * init_count += 1 ; < M as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 12) . cast () }) ? ; * init_count += 1 ; < N as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 13) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , L : crate :: SchemaWrite , M : crate :: SchemaWrite , N : crate :: SchemaWrite , O : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , L :: Src : Sized , M :: Src : Sized , N :: Src : Sized , O :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src , L :: Src , M :: Src , N :: Src , O :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ? + < L as crate :: SchemaWrite > :: size_of (& value . 11) ? + < M as crate :: SchemaWrite > :: size_of (& value . 12) ? + < N as crate :: SchemaWrite > :: size_of (& value . 13) ? + < O as crate :: SchemaWrite > :: size_of (& value . 14) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; < L as crate :: SchemaWrite > :: write (writer , & value . 11) ? ; < M as crate :: SchemaWrite > :: write (writer , & value . 12) ? ; < N as crate :: SchemaWrite > :: write (writer , & value . 13) ? ; < O as crate :: SchemaWrite > :: write (writer , & value . 14) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K , L , M , N , O > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , L : crate :: SchemaRead < 'de > , M : crate :: SchemaRead < 'de > , N : crate :: SchemaRead < 'de > , O : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst , L :: Dst , M :: Dst , N :: Dst , O :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O) , } impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 11u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 12u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 13u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 14u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 13) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; * init_count += 1 ; < L as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 11) . cast () }) ? ; * init_count += 1 ; < M as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 12) . cast () }) ? ; * init_count += 1 ; < N as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 13) . cast () }) ? ; * init_count += 1 ; < O as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 14) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P > crate :: SchemaWrite for (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P) where A : crate :: SchemaWrite , B : crate :: SchemaWrite , C : crate :: SchemaWrite , D : crate :: SchemaWrite , E : crate :: SchemaWrite , F : crate :: SchemaWrite , G : crate :: SchemaWrite , H : crate :: SchemaWrite , I : crate :: SchemaWrite , J : crate :: SchemaWrite , K : crate :: SchemaWrite , L : crate :: SchemaWrite , M : crate :: SchemaWrite , N : crate :: SchemaWrite , O : crate :: SchemaWrite , P : crate :: SchemaWrite , A :: Src : Sized , B :: Src : Sized , C :: Src : Sized , D :: Src : Sized , E :: Src : Sized , F :: Src : Sized , G :: Src : Sized , H :: Src : Sized , I :: Src : Sized , J :: Src : Sized , K :: Src : Sized , L :: Src : Sized , M :: Src : Sized , N :: Src : Sized , O :: Src : Sized , P :: Src : Sized , { type Src = (A :: Src , B :: Src , C :: Src , D :: Src , E :: Src , F :: Src , G :: Src , H :: Src , I :: Src , J :: Src , K :: Src , L :: Src , M :: Src , N :: Src , O :: Src , P :: Src) ; # [inline] # [allow (clippy :: arithmetic_side_effects)] fn size_of (value : & Self :: Src) -> crate :: WriteResult < usize > { Ok (< A as crate :: SchemaWrite > :: size_of (& value . 0) ? + < B as crate :: SchemaWrite > :: size_of (& value . 1) ? + < C as crate :: SchemaWrite > :: size_of (& value . 2) ? + < D as crate :: SchemaWrite > :: size_of (& value . 3) ? + < E as crate :: SchemaWrite > :: size_of (& value . 4) ? + < F as crate :: SchemaWrite > :: size_of (& value . 5) ? + < G as crate :: SchemaWrite > :: size_of (& value . 6) ? + < H as crate :: SchemaWrite > :: size_of (& value . 7) ? + < I as crate :: SchemaWrite > :: size_of (& value . 8) ? + < J as crate :: SchemaWrite > :: size_of (& value . 9) ? + < K as crate :: SchemaWrite > :: size_of (& value . 10) ? + < L as crate :: SchemaWrite > :: size_of (& value . 11) ? + < M as crate :: SchemaWrite > :: size_of (& value . 12) ? + < N as crate :: SchemaWrite > :: size_of (& value . 13) ? + < O as crate :: SchemaWrite > :: size_of (& value . 14) ? + < P as crate :: SchemaWrite > :: size_of (& value . 15) ?) } # [inline] fn write (writer : & mut crate :: io :: Writer , value : & Self :: Src) -> crate :: WriteResult < () > { < A as crate :: SchemaWrite > :: write (writer , & value . 0) ? ; < B as crate :: SchemaWrite > :: write (writer , & value . 1) ? ; < C as crate :: SchemaWrite > :: write (writer , & value . 2) ? ; < D as crate :: SchemaWrite > :: write (writer , & value . 3) ? ; < E as crate :: SchemaWrite > :: write (writer , & value . 4) ? ; < F as crate :: SchemaWrite > :: write (writer , & value . 5) ? ; < G as crate :: SchemaWrite > :: write (writer , & value . 6) ? ; < H as crate :: SchemaWrite > :: write (writer , & value . 7) ? ; < I as crate :: SchemaWrite > :: write (writer , & value . 8) ? ; < J as crate :: SchemaWrite > :: write (writer , & value . 9) ? ; < K as crate :: SchemaWrite > :: write (writer , & value . 10) ? ; < L as crate :: SchemaWrite > :: write (writer , & value . 11) ? ; < M as crate :: SchemaWrite > :: write (writer , & value . 12) ? ; < N as crate :: SchemaWrite > :: write (writer , & value . 13) ? ; < O as crate :: SchemaWrite > :: write (writer , & value . 14) ? ; < P as crate :: SchemaWrite > :: write (writer , & value . 15) ? ; Ok (()) } } impl < 'de , A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P > crate :: SchemaRead < 'de > for (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P) where A : crate :: SchemaRead < 'de > , B : crate :: SchemaRead < 'de > , C : crate :: SchemaRead < 'de > , D : crate :: SchemaRead < 'de > , E : crate :: SchemaRead < 'de > , F : crate :: SchemaRead < 'de > , G : crate :: SchemaRead < 'de > , H : crate :: SchemaRead < 'de > , I : crate :: SchemaRead < 'de > , J : crate :: SchemaRead < 'de > , K : crate :: SchemaRead < 'de > , L : crate :: SchemaRead < 'de > , M : crate :: SchemaRead < 'de > , N : crate :: SchemaRead < 'de > , O : crate :: SchemaRead < 'de > , P : crate :: SchemaRead < 'de > , { type Dst = (A :: Dst , B :: Dst , C :: Dst , D :: Dst , E :: Dst , F :: Dst , G :: Dst , H :: Dst , I :: Dst , J :: Dst , K :: Dst , L :: Dst , M :: Dst , N :: Dst , O :: Dst , P :: Dst) ; # [inline] # [allow (clippy :: arithmetic_side_effects , clippy :: type_complexity)] fn read (reader : & mut crate :: io :: Reader < 'de > , dst : & mut core :: mem :: MaybeUninit < Self :: Dst >) -> crate :: ReadResult < () > { let dst_ptr = dst . as_mut_ptr () ; struct DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P > { init_count : u8 , dst_ptr : * mut (A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P) , } impl < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P > Drop for DropGuard < A , B , C , D , E , F , G , H , I , J , K , L , M , N , O , P > { # [cold] fn drop (& mut self) { let dst_ptr = self . dst_ptr ; match self . init_count { 0u8 => { } , 1u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 2u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 3u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 4u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 5u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 6u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 7u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 8u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 9u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 10u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 11u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 12u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 13u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 14u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 13) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , 15u8 => { unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 14) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 13) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 12) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 11) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 10) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 9) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 8) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 7) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 6) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 5) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 4) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 3) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 2) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 1) ; } unsafe { core :: ptr :: drop_in_place (& raw mut (* dst_ptr) . 0) ; } } , _ => { debug_assert ! (false , "init_count out of bounds") ; } , } } } let mut guard = DropGuard { init_count : 0 , dst_ptr } ; let init_count = & mut guard . init_count ; < A as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 0) . cast () }) ? ; * init_count += 1 ; < B as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 1) . cast () }) ? ; * init_count += 1 ; < C as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 2) . cast () }) ? ; * init_count += 1 ; < D as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 3) . cast () }) ? ; * init_count += 1 ; < E as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 4) . cast () }) ? ; * init_count += 1 ; < F as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 5) . cast () }) ? ; * init_count += 1 ; < G as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 6) . cast () }) ? ; * init_count += 1 ; < H as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 7) . cast () }) ? ; * init_count += 1 ; < I as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 8) . cast () }) ? ; * init_count += 1 ; < J as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 9) . cast () }) ? ; * init_count += 1 ; < K as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 10) . cast () }) ? ; * init_count += 1 ; < L as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 11) . cast () }) ? ; * init_count += 1 ; < M as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 12) . cast () }) ? ; * init_count += 1 ; < N as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 13) . cast () }) ? ; * init_count += 1 ; < O as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 14) . cast () }) ? ; * init_count += 1 ; < P as crate :: SchemaRead < 'de >> :: read (reader , unsafe { & mut * (& raw mut (* dst_ptr) . 15) . cast () }) ? ; core :: mem :: forget (guard) ; Ok (()) } }
\ No newline at end of file